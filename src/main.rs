@@ -3,18 +3,20 @@ pub mod utils;
 
 use bevy::{prelude::*, window::PresentMode};
 use bevy_inspector_egui::WorldInspectorPlugin;
-use bevy_mod_raycast::{
-    DefaultPluginState, DefaultRaycastingPlugin, RayCastMesh, RayCastSource, RaycastSystem,
-};
+use bevy_mod_raycast::{DefaultPluginState, DefaultRaycastingPlugin, RayCastSource, RaycastSystem};
 use bevy_rapier3d::{
-    prelude::{Collider, Group, NoUserData, RapierPhysicsPlugin},
+    prelude::{Group, NoUserData, RapierPhysicsPlugin},
     render::RapierDebugRenderPlugin,
 };
 use bevy_text_mesh::TextMeshPlugin;
 use plugins::{
     camera::camera_follow_player,
     combat::combat_plugin::CombatPlugin,
+    crafting::crafting_plugin::CraftingPlugin,
     creature::creature_plugin::CreaturePlugin,
+    exploration::exploration_plugin::ExplorationPlugin,
+    farming::farming_plugin::FarmingPlugin,
+    interaction::interaction_plugin::InteractionPlugin,
     items::items_plugin::ItemsPlugin,
     location::LocationPlugin,
     player::{
@@ -22,27 +24,142 @@ use plugins::{
         player_plugin::PlayerPlugin,
     },
     ui::UiPlugin,
+    world::{map_plugin::MapPlugin, world_plugin::WorldPlugin},
 };
 
 pub const CAMERA_VEC_OFFSET: f32 = 30.;
 pub const CAMERA_VEC_OFFSET_VEC: Vec3 = Vec3::new(0., CAMERA_VEC_OFFSET, CAMERA_VEC_OFFSET);
 
 pub const SWORD_SLASH_TIME: f32 = 0.6;
+pub const INPUT_BUFFER_TIME: f32 = 0.3;
+pub const COMBO_WINDOW: f32 = 0.5;
+pub const HEAVY_CHARGE_TIME: f32 = 0.8;
+pub const HEAVY_WINDUP_ARC: f32 = 1.;
+pub const BOW_MAX_DRAW_TIME: f32 = 1.5;
+pub const BOW_MIN_DRAW_POWER: f32 = 0.3;
+pub const ARROW_DAMAGE: f32 = 30.;
 
 pub const HUMAN_STEP_DISTANCE: f32 = 10.;
 pub const MONSTER_STEP_DISTANCE: f32 = 5.;
+pub const SPRINT_SPEED_FACTOR: f32 = 1.6;
+pub const EXHAUSTED_SPEED_FACTOR: f32 = 0.6;
+
+pub const HUMAN_MAX_STAMINA: f32 = 100.;
+pub const MONSTER_MAX_STAMINA: f32 = 60.;
+pub const STAMINA_REGEN: f32 = 25.;
+pub const STAMINA_REGEN_DELAY: f32 = 1.;
+pub const STAMINA_EXHAUSTION_THRESHOLD: f32 = 0.3;
+pub const SPRINT_STAMINA_COST: f32 = 20.;
+
+pub const DODGE_SPEED: f32 = 25.;
+pub const DODGE_TIME: f32 = 0.3;
+pub const DODGE_IFRAMES_TIME: f32 = 0.25;
+pub const DODGE_COOLDOWN: f32 = 0.8;
+pub const DODGE_STAMINA_COST: f32 = 25.;
+
+pub const BLOCK_STAMINA_COST: f32 = 15.;
+pub const BLOCK_DAMAGE_FACTOR: f32 = 0.3;
+
+pub const JUMP_SPEED: f32 = 7.;
+pub const JUMP_STAMINA_COST: f32 = 10.;
+pub const MAX_CLIMB_ANGLE: f32 = 40. * std::f32::consts::PI / 180.;
+pub const GROUND_PROBE_DISTANCE: f32 = 0.3;
+pub const PATH_PROBE_DISTANCE: f32 = 3.;
 
 pub const HUMAN_MAX_RANGE: f32 = 3.;
 pub const MONSTER_MAX_RANGE: f32 = 3.;
 
 pub const HUMAN_ATK: f32 = 20.;
 pub const MONSTER_ATK: f32 = 7.;
-pub const PROJECTILE_IMPULSE: f32 = 0.9;
-pub const MONSTER_HIT_IMPULSE: f32 = 20.;
+pub const STUCK_PROJECTILE_TIME: f32 = 10.;
+pub const FRIENDLY_FIRE: bool = false;
+pub const CREATURE_DENSITY: f32 = 2000.;
+pub const KNOCKBACK_REFERENCE_MASS: f32 = 3000.;
+pub const EXPLOSION_KNOCKBACK: f32 = 12.;
+pub const EXPLOSION_STAGGER: f32 = 1.;
 
 pub const MONSTER_ATTACK_COOLDOWN: f32 = 2.;
 pub const MONSTER_STUN_COOLDOWN: f32 = 2.;
+pub const HEAD_STUN_TIME: f32 = 3.;
 pub const MONSTER_AGGRO_DISTANCE: f32 = 16.;
+pub const MONSTER_RANGED_DISTANCE: f32 = 12.;
+pub const MONSTER_RANGED_COOLDOWN: f32 = 3.;
+// A monster out of four throws rocks
+pub const MONSTER_RANGED_CHANCE: f64 = 0.25;
+pub const TARGET_LOCK_RANGE: f32 = 20.;
+pub const TARGET_HIGHLIGHT_COLOR: Color = Color::rgb(0.6, 0.6, 0.);
+
+pub const CRAFTING_STATION_RANGE: f32 = 4.;
+pub const HARVEST_DAMAGE: f32 = 25.;
+
+pub const BELT_SIZE: usize = 4;
+pub const INVENTORY_SLOTS: usize = 20;
+pub const INVENTORY_MAX_WEIGHT: f32 = 100.;
+pub const CONSUMABLE_COOLDOWN: f32 = 1.;
+pub const BOMB_COOLDOWN: f32 = 3.;
+pub const SPEAR_COOLDOWN: f32 = 0.8;
+pub const AXE_COOLDOWN: f32 = 0.9;
+pub const BOW_COOLDOWN: f32 = 0.5;
+pub const TOOL_COOLDOWN: f32 = 0.8;
+
+pub const WORLD_SEED: u64 = 42;
+pub const ASSETS_FOLDER: &str = "assets";
+pub const MAPS_FOLDER: &str = "maps";
+pub const STARTING_MAPS: [&str; 1] = ["spawn_camp"];
+pub const ZONES_FOLDER: &str = "zones";
+// Zones apart from the overworld, from `assets/zones/<name>.ron`
+pub const ZONES: [&str; 1] = ["crypt"];
+pub const OVERWORLD_ZONE: &str = "overworld";
+pub const PORTAL_COOLDOWN: f32 = 2.;
+// Distance outside the zone walls where creatures still belong to the zone
+pub const ZONE_BOUNDS_MARGIN: f32 = 5.;
+pub const CHUNK_SIZE: f32 = 40.;
+pub const WORLD_CHUNK_RADIUS: i32 = 7;
+pub const BIOME_REGION_CHUNKS: i32 = 3;
+pub const SPAWN_ZONE_RADIUS: f32 = 8.;
+pub const SPAWN_ZONE_COOLDOWN: f32 = 10.;
+pub const WORLD_MIN_SPACING: f32 = 4.;
+pub const GROUND_LEVEL: f32 = -1.9;
+pub const TERRAIN_RESOLUTION: usize = 16;
+pub const TERRAIN_LATTICE_SIZE: f32 = 10.;
+pub const WORLD_WALL_HEIGHT: f32 = 50.;
+pub const SAFE_ZONE_REGEN: f32 = 5.;
+pub const SAFE_ZONE_MARGIN: f32 = 1.;
+// Seconds in a full day
+pub const DAY_LENGTH: f32 = 600.;
+// Time of day when the game starts, between 0 (midnight) and 1
+pub const DAY_START_TIME: f32 = 0.3;
+pub const SUN_ILLUMINANCE: f32 = 50000.;
+pub const AMBIENT_DAY_BRIGHTNESS: f32 = 0.4;
+pub const AMBIENT_NIGHT_BRIGHTNESS: f32 = 0.05;
+// Aggro distance multiplier of monsters outside of their active period
+pub const INACTIVE_AGGRO_FACTOR: f32 = 0.4;
+// Seconds a weather lasts before it may change
+pub const WEATHER_MIN_DURATION: f32 = 120.;
+pub const WEATHER_MAX_DURATION: f32 = 300.;
+pub const FOG_SIGHT_FACTOR: f32 = 0.5;
+pub const STORM_SIGHT_FACTOR: f32 = 0.75;
+pub const RAIN_SPEED_FACTOR: f32 = 0.85;
+pub const STORM_SPEED_FACTOR: f32 = 0.7;
+// Seconds a creature put out by the rain cannot burn again
+pub const RAIN_BURN_IMMUNITY: f32 = 2.;
+pub const STORM_SPAWN_COOLDOWN: f32 = 20.;
+// Maximum number of storm monsters in a region
+pub const STORM_MONSTERS: usize = 3;
+pub const STORM_SPAWN_DISTANCE: f32 = 20.;
+// Side of the cells of the exploration grid
+pub const EXPLORATION_CELL_SIZE: f32 = 10.;
+// Distance around the players where the cells are explored
+pub const EXPLORATION_RADIUS: f32 = 15.;
+pub const EXPLORATION_SAVE_FOLDER: &str = "saves/exploration";
+pub const EXPLORATION_SAVE_INTERVAL: f32 = 10.;
+pub const MINIMAP_SIZE: f32 = 180.;
+// Distance from which the players interact with chests, doors and levers
+pub const INTERACTION_RANGE: f32 = 3.;
+pub const CHUNK_LOAD_RADIUS: i32 = 2;
+pub const CHUNK_UNLOAD_RADIUS: i32 = 3;
+pub const SIMULATION_DISTANCE: f32 = 60.;
+pub const DORMANT_DISTANCE: f32 = 70.;
 
 pub const WORLD_WIDTH: f32 = 800.0;
 pub const WORLD_HEIGHT: f32 = 400.0;
@@ -60,6 +177,10 @@ pub const MONSTER_GROUP: Group = Group::GROUP_2;
 pub const SWORD_SENSOR_GROUP: Group = Group::GROUP_3;
 pub const PROJECTILE_GROUP: Group = Group::GROUP_4;
 pub const PICKABLE_GROUP: Group = Group::GROUP_5;
+pub const RESOURCE_NODE_GROUP: Group = Group::GROUP_6;
+// The ground and walls keep the default groups, which include this one
+pub const TERRAIN_GROUP: Group = Group::GROUP_7;
+pub const HIT_ZONE_GROUP: Group = Group::GROUP_8;
 
 #[derive(Clone, Eq, PartialEq, Debug, Hash)]
 enum GameState {
@@ -88,36 +209,6 @@ fn setup_graphics(mut commands: Commands) {
             ..Default::default()
         })
         .insert(RayCastSource::<MouseRaycastSet>::new());
-
-    commands.spawn_bundle(PointLightBundle {
-        point_light: PointLight {
-            intensity: 300000.,
-            range: 90.,
-            shadows_enabled: true,
-            ..default()
-        },
-        transform: Transform::from_xyz(4.0, 50.0, 4.0),
-        ..default()
-    });
-}
-
-fn setup_physics(
-    mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-) {
-    /* Create the ground. */
-    commands
-        .spawn()
-        .insert(Collider::cuboid(300.0, 0.1, 300.0))
-        .insert_bundle(TransformBundle::from(Transform::from_xyz(0.0, -2.0, 0.0)))
-        .insert_bundle(PbrBundle {
-            mesh: meshes.add(Mesh::from(shape::Box::new(600., 0.2, 600.))),
-            material: materials.add(Color::rgb(0.3, 0.5, 0.3).into()),
-            transform: Transform::from_xyz(0., 0., 0.),
-            ..default()
-        })
-        .insert(RayCastMesh::<MouseRaycastSet>::default());
 }
 
 fn main() {
@@ -145,10 +236,11 @@ fn main() {
         )
         .add_plugin(WorldInspectorPlugin::new())
         .add_startup_system(setup_graphics)
-        .add_startup_system(setup_physics)
         .add_plugin(RapierPhysicsPlugin::<NoUserData>::default())
         .add_plugin(RapierDebugRenderPlugin::default())
         .add_plugin(TextMeshPlugin)
+        .add_plugin(WorldPlugin)
+        .add_plugin(MapPlugin)
         .add_state(GameState::Playing)
         .add_plugin(CreaturePlugin)
         .add_plugin(PlayerPlugin)
@@ -157,6 +249,10 @@ fn main() {
         .add_plugin(CombatPlugin)
         .add_plugin(UiPlugin)
         .add_plugin(ItemsPlugin)
+        .add_plugin(CraftingPlugin)
+        .add_plugin(FarmingPlugin)
+        .add_plugin(ExplorationPlugin)
+        .add_plugin(InteractionPlugin)
         // .add_plugin(HudPlugin)
         .add_system(bevy::window::close_on_esc)
         .run();
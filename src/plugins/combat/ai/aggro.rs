@@ -1,16 +1,28 @@
 use bevy::prelude::*;
 
-use crate::{
-    plugins::{
-        creature::creature_plugin::{Monster, Player},
-        location::Location,
+use crate::plugins::{
+    creature::{
+        creature_plugin::{Activity, Monster, Player},
+        systems::stats::{BrainState, ConsciousnessStateEnum},
+    },
+    location::Location,
+    world::{
+        clock::WorldClock,
+        systems::safe_zones::in_safe_zone,
+        weather::RegionalWeather,
+        world_plugin::{Dormant, SafeZone},
     },
-    MONSTER_AGGRO_DISTANCE,
 };
 
 pub fn monster_aggro_system(
-    mut monsters_query: Query<&mut Location, (With<Monster>, Without<Player>)>,
+    clock: Res<WorldClock>,
+    regional_weather: Res<RegionalWeather>,
+    mut monsters_query: Query<
+        (&mut Location, &mut BrainState, &Activity),
+        (With<Monster>, Without<Player>, Without<Dormant>),
+    >,
     player_query: Query<&Location, With<Player>>,
+    safe_zone_query: Query<(&GlobalTransform, &SafeZone)>,
 ) {
     // Get player position
     let player_position = match player_query.get_single() {
@@ -21,17 +33,31 @@ pub fn monster_aggro_system(
         Err(_) => return,
     };
 
+    let player_safe = in_safe_zone(safe_zone_query.iter(), player_position);
+
     // for each monster -> get distance from player
-    for mut location in monsters_query.iter_mut() {
+    for (mut location, mut brain_state, activity) in monsters_query.iter_mut() {
         let position = match location.position {
             Some(position) => position,
             None => continue,
         };
 
-        // if distance <= AGGRO_DISTANCE
-        if position.abs_diff_eq(player_position, MONSTER_AGGRO_DISTANCE) {
+        // if distance <= AGGRO_DISTANCE, shorter outside of the monster active period and in the fog
+        let aggro_distance = activity.aggro_distance(clock.period())
+            * regional_weather.at(position).sight_multiplier();
+        if position.abs_diff_eq(player_position, aggro_distance) {
+            // A sleeping monster wakes up when a player comes close
+            if brain_state.conscious == ConsciousnessStateEnum::Asleep && !player_safe {
+                brain_state.conscious = ConsciousnessStateEnum::Awake;
+            }
+
             // monster location.destination = player.location.position
-            location.destination = Some(player_position);
+            // Monsters give up on players taking shelter in a safe zone
+            location.destination = if player_safe {
+                None
+            } else {
+                Some(player_position)
+            };
         }
     }
 }
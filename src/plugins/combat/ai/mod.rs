@@ -1,3 +1,4 @@
 pub mod aggro;
 pub mod monster_attack;
+pub mod monster_ranged_attack;
 pub mod receive_damages;
@@ -1,56 +1,83 @@
 use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
 
-use crate::{
-    plugins::{
-        creature::{
-            creature_plugin::{Monster, Player},
-            systems::stats::{LastAttack, Stats},
+use crate::plugins::{
+    combat::combat_events::{Hit, HitPlayerEvent},
+    creature::{
+        creature_plugin::{Monster, Player},
+        systems::{
+            hit_zones::HitZoneType,
+            sensors::WeaponHitbox,
+            stamina::Stamina,
+            stats::{BrainState, ConsciousnessStateEnum, LastAttack, Staggered, Stats},
+            status_effects::StatusEffects,
         },
-        location::Location,
-        player::player_events::KillPlayerEvent,
     },
-    MONSTER_MAX_RANGE,
+    items::items_plugin::ItemType,
+    world::world_plugin::Dormant,
 };
-// Monster attack a player
+// Monster attack a player, sleeping and stunned monsters do not attack
 pub fn monster_fight_system(
     time: Res<Time>,
+    rapier_context: Res<RapierContext>,
     mut monsters_query: Query<
-        (&Location, &Stats, &mut LastAttack),
-        (With<Monster>, Without<Player>),
+        (
+            &Transform,
+            &Stats,
+            &StatusEffects,
+            &BrainState,
+            &mut Stamina,
+            &mut LastAttack,
+        ),
+        (
+            With<Monster>,
+            Without<Player>,
+            Without<Staggered>,
+            Without<Dormant>,
+        ),
     >,
-    mut player_query: Query<(&Location, &mut Stats), With<Player>>,
-    mut ev_kill_player: EventWriter<KillPlayerEvent>,
+    hitbox_query: Query<(Entity, &Parent), With<WeaponHitbox>>,
+    player_query: Query<Entity, With<Player>>,
+    mut ev_hit_player: EventWriter<HitPlayerEvent>,
 ) {
-    // Get player position
-    let (player_position, mut player_stats) = match player_query.get_single_mut() {
-        Ok(tuple) => match tuple.0.position {
-            Some(position) => (position, tuple.1),
-            None => return,
-        },
+    // Get player
+    let player = match player_query.get_single() {
+        Ok(player) => player,
         Err(_) => return,
     };
 
-    // for each monster -> get distance from player
-    for (location, stats, mut last_attack) in monsters_query.iter_mut() {
-        let position = match location.position {
-            Some(position) => position,
-            None => continue,
-        };
+    // for each monster hitbox -> check if the player is inside
+    for (hitbox, parent) in hitbox_query.iter() {
+        let (transform, stats, status_effects, brain_state, mut stamina, mut last_attack) =
+            match monsters_query.get_mut(parent.get()) {
+                Ok(monster) => monster,
+                Err(_) => continue,
+            };
+
+        if brain_state.conscious != ConsciousnessStateEnum::Awake {
+            continue;
+        }
 
-        // if distance <= MONSTER_MAX_RANGE
-        if position.abs_diff_eq(player_position, MONSTER_MAX_RANGE)
+        // if player in the monster weapon reach
+        if rapier_context.intersection_pair(hitbox, player) == Some(true)
             && last_attack.0.tick(time.delta()).finished()
+            && stamina.try_consume(ItemType::Claws.stamina_cost())
         {
             // Reset monster timer
             last_attack.0.reset();
 
             // monster to attack player
-            player_stats.hp -= stats.atk;
-
-            if player_stats.hp <= 0. {
-                player_stats.hp = 0.;
-                ev_kill_player.send(KillPlayerEvent());
-            }
+            ev_hit_player.send(HitPlayerEvent(
+                player,
+                Hit {
+                    origin: transform.translation,
+                    damage: stats.atk * status_effects.atk_multiplier(),
+                    zone: HitZoneType::Body,
+                    knockback: ItemType::Claws.knockback(),
+                    stagger: ItemType::Claws.stagger_time(),
+                    status: ItemType::Claws.on_hit_status(),
+                },
+            ));
         }
     }
 }
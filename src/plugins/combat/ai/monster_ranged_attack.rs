@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::{
+    plugins::{
+        combat::{combat_events::FireProjectileEvent, weapons::range::projectile::ProjectileType},
+        creature::{
+            creature_plugin::{Monster, Player},
+            systems::stats::{BrainState, ConsciousnessStateEnum, Staggered},
+        },
+        location::Location,
+        world::{
+            systems::safe_zones::in_safe_zone,
+            world_plugin::{Dormant, SafeZone},
+        },
+    },
+    MONSTER_RANGED_CHANCE, MONSTER_RANGED_COOLDOWN, MONSTER_RANGED_DISTANCE,
+};
+
+/// Monsters throwing projectiles at the player from a distance
+#[derive(Component)]
+pub struct RangedAttack {
+    pub projectile_type: ProjectileType,
+    pub cooldown: Timer,
+}
+
+impl RangedAttack {
+    pub fn new(projectile_type: ProjectileType) -> Self {
+        Self {
+            projectile_type,
+            cooldown: Timer::from_seconds(MONSTER_RANGED_COOLDOWN, false),
+        }
+    }
+
+    /// Projectile thrown by a newly spawned monster, if it is one of the ranged monsters
+    pub fn roll(rng: &mut impl Rng) -> Option<ProjectileType> {
+        rng.gen_bool(MONSTER_RANGED_CHANCE)
+            .then_some(ProjectileType::Rock)
+    }
+}
+
+// Monster fire at a player
+pub fn monster_ranged_attack_system(
+    time: Res<Time>,
+    mut monsters_query: Query<
+        (Entity, &Location, &BrainState, &mut RangedAttack),
+        (
+            With<Monster>,
+            Without<Player>,
+            Without<Staggered>,
+            Without<Dormant>,
+        ),
+    >,
+    player_query: Query<&Location, With<Player>>,
+    safe_zone_query: Query<(&GlobalTransform, &SafeZone)>,
+    mut ev_fire_projectile: EventWriter<FireProjectileEvent>,
+) {
+    // Get player position
+    let player_position = match player_query.get_single() {
+        Ok(location) => match location.position {
+            Some(position) => position,
+            None => return,
+        },
+        Err(_) => return,
+    };
+
+    // Players in a safe zone cannot be targeted
+    if in_safe_zone(safe_zone_query.iter(), player_position) {
+        return;
+    }
+
+    for (monster, location, brain_state, mut ranged_attack) in monsters_query.iter_mut() {
+        let position = match location.position {
+            Some(position) => position,
+            None => continue,
+        };
+
+        if !ranged_attack.cooldown.tick(time.delta()).finished()
+            || !matches!(brain_state.conscious, ConsciousnessStateEnum::Awake)
+        {
+            continue;
+        }
+
+        // if distance <= MONSTER_RANGED_DISTANCE
+        if position.distance(player_position) <= MONSTER_RANGED_DISTANCE {
+            ranged_attack.cooldown.reset();
+            ev_fire_projectile.send(FireProjectileEvent(
+                monster,
+                player_position,
+                1.,
+                ranged_attack.projectile_type,
+            ));
+        }
+    }
+}
@@ -1,65 +1,230 @@
 use crate::{
     plugins::{
-        combat::combat_events::{HitMonsterEvent, KillMonsterEvent},
+        combat::{
+            block::Blocking,
+            combat_events::{
+                DamageResultEvent, Hit, HitMonsterEvent, HitPlayerEvent, KillMonsterEvent,
+            },
+            dodge::Invulnerable,
+        },
         creature::{
-            creature_plugin::{Monster, Player},
-            systems::stats::{BrainState, ConsciousnessStateEnum, Stats},
+            creature_plugin::{CreatureType, Monster, Player},
+            systems::{
+                hit_zones::HitZoneType,
+                stamina::Stamina,
+                stats::{BrainState, ConsciousnessStateEnum, Staggered, Stats},
+                status_effects::{StatusEffectType, StatusEffects},
+            },
         },
+        player::player_events::KillPlayerEvent,
     },
-    MONSTER_HIT_IMPULSE,
+    BLOCK_DAMAGE_FACTOR, BLOCK_STAMINA_COST, HEAD_STUN_TIME, MONSTER_STUN_COOLDOWN,
 };
 use bevy::prelude::*;
 use bevy_rapier3d::prelude::*;
+use std::collections::HashSet;
+
+/**
+ * Push the victim away from the attacker and stagger it, the velocity is kept
+ * until the stagger ends as movement systems ignore staggered creatures
+ */
+fn knock_back(
+    commands: &mut Commands,
+    victim: Entity,
+    hit: &Hit,
+    transform: &Transform,
+    creature_type: &CreatureType,
+    velocity: &mut Velocity,
+) {
+    let knockback = hit.knockback_velocity(transform.translation, creature_type.mass());
+    velocity.linvel = Vec3::new(knockback.x, velocity.linvel.y, knockback.z);
+
+    commands
+        .entity(victim)
+        .insert(Staggered(Timer::from_seconds(hit.stagger, false)));
+}
+
+/**
+ * Damages of the hit on the victim, and the status effects it applies,
+ * legs hits slow the victim down
+ */
+fn apply_hit(hit: &Hit, status_effects: &mut StatusEffects) -> f32 {
+    let damage =
+        hit.damage * hit.zone.damage_multiplier() * status_effects.damage_taken_multiplier();
+
+    if let Some(effect) = hit.status {
+        status_effects.apply(effect);
+    }
+
+    if hit.zone == HitZoneType::Legs {
+        status_effects.apply(StatusEffectType::Slow);
+    }
+
+    damage
+}
 
 // Player hit a monster
 pub fn monster_hit_system(
     mut commands: Commands,
 
     mut monsters_q: Query<
-        (&mut Stats, &mut BrainState, &mut ExternalImpulse),
+        (
+            &Transform,
+            &CreatureType,
+            &mut Stats,
+            &mut StatusEffects,
+            &mut BrainState,
+            &mut Velocity,
+            Option<&Invulnerable>,
+        ),
         (With<Monster>, Without<Player>),
     >,
-    player_q: Query<&Stats, (With<Player>, Without<Monster>)>,
-
     mut ev_hit_monster: EventReader<HitMonsterEvent>,
     mut ev_kill_monster: EventWriter<KillMonsterEvent>,
+    mut ev_damage_result: EventWriter<DamageResultEvent>,
 ) {
-    // Get player stats
-    let player_stats = match player_q.get_single() {
-        Ok(stats) => stats,
-        Err(_) => return,
-    };
-
     for ev in ev_hit_monster.iter() {
         println!("event hit monster");
 
-        let (mut stats, mut brain_state, mut external_impulse) = match monsters_q.get_mut(ev.0) {
+        let (
+            transform,
+            creature_type,
+            mut stats,
+            mut status_effects,
+            mut brain_state,
+            mut velocity,
+            invulnerable,
+        ) = match monsters_q.get_mut(ev.0) {
             Ok(tupl) => tupl,
-            Err(_) => return,
+            Err(_) => continue,
         };
 
-        println!(
-            "Applied impulse vector {:?}",
-            Vec2::from_angle(ev.1).normalize()
-        );
+        // Dodging monsters ignore the hit, a monster killed earlier in the frame only dies once
+        if stats.hp <= 0. || invulnerable.is_some() {
+            continue;
+        }
 
-        let vec_destination = Vec2::from_angle(ev.1).normalize();
-        let looking_at = Vec3::new(vec_destination.x, 1., vec_destination.y);
-        let impulse = Transform::default()
-            .looking_at(looking_at, Vec3::ZERO)
-            .translation
-            * MONSTER_HIT_IMPULSE;
+        let hit = &ev.1;
+        knock_back(
+            &mut commands,
+            ev.0,
+            hit,
+            transform,
+            creature_type,
+            &mut velocity,
+        );
 
-        external_impulse.impulse = impulse;
-        stats.hp -= player_stats.atk;
+        let zone = hit.zone;
+        let damage = apply_hit(hit, &mut status_effects);
+        stats.hp -= damage;
+        ev_damage_result.send(DamageResultEvent(ev.0, zone, damage, zone.is_critical()));
 
-        brain_state.conscious = ConsciousnessStateEnum::Stun;
-        brain_state.stun_at.reset();
+        // Head hits stun longer
+        match zone {
+            HitZoneType::Head => brain_state.stun(HEAD_STUN_TIME),
+            _ => brain_state.stun(MONSTER_STUN_COOLDOWN),
+        }
 
         if stats.hp <= 0. {
             ev_kill_monster.send(KillMonsterEvent(ev.0));
             brain_state.conscious = ConsciousnessStateEnum::Ko;
-            commands.entity(ev.0).despawn_recursive();
+        }
+    }
+}
+
+/// # kill_monster_system
+/// Despawn the monsters killed during the frame, the only place where monsters die
+///
+/// Runs in PostUpdate so the commands queued on the monster by the update systems,
+/// e.g. a stagger from a hit received in the same frame, are applied before the despawn
+///
+pub fn kill_monster_system(
+    mut commands: Commands,
+    monster_q: Query<(), With<Monster>>,
+    mut ev_kill_monster: EventReader<KillMonsterEvent>,
+) {
+    let mut killed = HashSet::new();
+
+    for KillMonsterEvent(monster) in ev_kill_monster.iter() {
+        if monster_q.contains(*monster) && killed.insert(*monster) {
+            commands.entity(*monster).despawn_recursive();
+        }
+    }
+}
+
+// Monster hit the player, head hits stun the player like the monsters
+// A blocked hit costs stamina, deals less damages and does not stun, exhaustion breaks the guard
+pub fn player_hit_system(
+    mut commands: Commands,
+    mut player_q: Query<
+        (
+            &Transform,
+            &CreatureType,
+            &mut Stats,
+            &mut StatusEffects,
+            &mut BrainState,
+            &mut Velocity,
+            &mut Stamina,
+            Option<&Invulnerable>,
+            Option<&Blocking>,
+        ),
+        (With<Player>, Without<Monster>),
+    >,
+    mut ev_hit_player: EventReader<HitPlayerEvent>,
+    mut ev_kill_player: EventWriter<KillPlayerEvent>,
+    mut ev_damage_result: EventWriter<DamageResultEvent>,
+) {
+    for ev in ev_hit_player.iter() {
+        let (
+            transform,
+            creature_type,
+            mut stats,
+            mut status_effects,
+            mut brain_state,
+            mut velocity,
+            mut stamina,
+            invulnerable,
+            blocking,
+        ) = match player_q.get_mut(ev.0) {
+            Ok(player) => player,
+            Err(_) => continue,
+        };
+
+        // The hit is dodged during the invulnerability frames
+        if stats.hp <= 0. || invulnerable.is_some() {
+            continue;
+        }
+
+        let hit = &ev.1;
+        knock_back(
+            &mut commands,
+            ev.0,
+            hit,
+            transform,
+            creature_type,
+            &mut velocity,
+        );
+
+        let blocked = blocking.is_some() && stamina.try_consume(BLOCK_STAMINA_COST);
+        if blocking.is_some() && stamina.exhausted {
+            commands.entity(ev.0).remove::<Blocking>();
+        }
+
+        let zone = hit.zone;
+        let mut damage = apply_hit(hit, &mut status_effects);
+        if blocked {
+            damage *= BLOCK_DAMAGE_FACTOR;
+        }
+        stats.hp -= damage;
+        ev_damage_result.send(DamageResultEvent(ev.0, zone, damage, zone.is_critical()));
+
+        if zone == HitZoneType::Head && !blocked {
+            brain_state.stun(HEAD_STUN_TIME);
+        }
+
+        if stats.hp <= 0. {
+            stats.hp = 0.;
+            ev_kill_player.send(KillPlayerEvent());
         }
     }
 }
@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+
+use crate::plugins::{
+    combat::{combat_events::BlockEvent, dodge::Dodging},
+    creature::{
+        creature_plugin::Creature,
+        systems::{stamina::Stamina, stats::Staggered},
+    },
+};
+
+/// Creature holding its guard up, each blocked hit costs BLOCK_STAMINA_COST
+#[derive(Component)]
+pub struct Blocking;
+
+/**
+ * Raise or lower the guard of the creature
+ *
+ * Staggered, rolling and exhausted creatures cannot raise their guard
+ */
+pub fn block_system(
+    mut commands: Commands,
+    creature_q: Query<(&Stamina, Option<&Staggered>, Option<&Dodging>), With<Creature>>,
+    mut ev_block: EventReader<BlockEvent>,
+) {
+    for BlockEvent(creature, raise) in ev_block.iter() {
+        let (stamina, staggered, dodging) = match creature_q.get(*creature) {
+            Ok(creature) => creature,
+            Err(_) => continue,
+        };
+
+        if !raise {
+            commands.entity(*creature).remove::<Blocking>();
+        } else if stamina.exhausted || staggered.is_some() || dodging.is_some() {
+            println!("Cannot block now");
+        } else {
+            commands.entity(*creature).insert(Blocking);
+        }
+    }
+}
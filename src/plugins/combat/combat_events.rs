@@ -1,7 +1,88 @@
 use bevy::prelude::*;
 
-pub struct FireProjectileEvent(pub Vec3);
+use super::weapons::range::projectile::ProjectileType;
+use crate::{
+    plugins::creature::systems::{hit_zones::HitZoneType, status_effects::StatusEffectType},
+    KNOCKBACK_REFERENCE_MASS,
+};
+
+/// Spawn a projectile flying from the shooter toward the target
+/// ### Param1 - {Entity} - The creature firing the projectile
+/// ### Param2 - {Vec3} - The targeted position
+/// ### Param3 - {f32} - The power of the shot, scaling speed and damages, up to 1
+/// ### Param4 - {ProjectileType} - The fired projectile
+pub struct FireProjectileEvent(pub Entity, pub Vec3, pub f32, pub ProjectileType);
+
+/// Start drawing the ranged item equipped by the creature
+pub struct DrawBowEvent(pub Entity);
+
+/// Release the drawn ranged item of the creature
+/// ### Param1 - {Entity} - The creature drawing the item
+/// ### Param2 - {Option<Vec3>} - The targeted position, None cancels the shot
+pub struct ReleaseBowEvent(pub Entity, pub Option<Vec3>);
 pub struct ProjectileHitEvent(pub Entity, pub Entity);
 
-pub struct HitMonsterEvent(pub Entity, pub f32);
+/// Roll the creature, ignoring the hits received during the first frames
+/// ### Param1 - {Entity} - The dodging creature
+/// ### Param2 - {Vec3} - The direction of the roll, only its horizontal part is used
+pub struct DodgeEvent(pub Entity, pub Vec3);
+
+/// Raise or lower the guard of the creature, blocked hits deal less damages but cost stamina
+/// ### Param1 - {Entity} - The blocking creature
+/// ### Param2 - {bool} - True raises the guard, false lowers it
+pub struct BlockEvent(pub Entity, pub bool);
+
+/// Lock a monster as the target of the creature
+/// ### Param1 - {Entity} - The creature locking a target
+/// ### Param2 - {Option<Entity>} - The monster to lock, None locks the nearest one or cycles to the next
+pub struct LockTargetEvent(pub Entity, pub Option<Entity>);
+
+/// Release the target locked by the creature
+pub struct ReleaseTargetEvent(pub Entity);
+
+/// A hit dealt to a creature
+pub struct Hit {
+    /// Position of the attacker, the victim is pushed away from it
+    pub origin: Vec3,
+    /// Base damages, before the hit zone multiplier
+    pub damage: f32,
+    pub zone: HitZoneType,
+    /// Knockback speed given to a creature of KNOCKBACK_REFERENCE_MASS
+    pub knockback: f32,
+    /// Seconds during which the victim can neither move nor attack
+    pub stagger: f32,
+    /// Status effect applied to the victim, unless it is immune
+    pub status: Option<StatusEffectType>,
+}
+
+impl Hit {
+    /// Horizontal velocity pushing the victim away from the attacker, lighter victims fly further
+    pub fn knockback_velocity(&self, victim_position: Vec3, victim_mass: f32) -> Vec3 {
+        let direction = (victim_position - self.origin) * Vec3::new(1., 0., 1.);
+        let mass_ratio = (KNOCKBACK_REFERENCE_MASS / victim_mass).clamp(0.25, 2.);
+
+        direction.normalize_or_zero() * self.knockback * mass_ratio
+    }
+}
+
+/// ### Param1 - {Entity} - The monster hit
+/// ### Param2 - {Hit} - The hit received
+pub struct HitMonsterEvent(pub Entity, pub Hit);
 pub struct KillMonsterEvent(pub Entity);
+
+/// ### Param1 - {Entity} - The player hit
+/// ### Param2 - {Hit} - The hit received
+pub struct HitPlayerEvent(pub Entity, pub Hit);
+
+/// Damages actually applied to a creature
+/// ### Param1 - {Entity} - The creature hit
+/// ### Param2 - {HitZoneType} - The zone which was hit
+/// ### Param3 - {f32} - The damages dealt, hit zone multiplier included
+/// ### Param4 - {bool} - Whether the hit was critical
+pub struct DamageResultEvent(pub Entity, pub HitZoneType, pub f32, pub bool);
+
+/// An explosion damaging monsters around its center
+/// ### Param1 - {Vec3} - The explosion center
+/// ### Param2 - {f32} - The explosion radius
+/// ### Param3 - {f32} - The damages dealt to every monster in the radius
+pub struct ExplosionEvent(pub Vec3, pub f32, pub f32);
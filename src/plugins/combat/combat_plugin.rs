@@ -1,16 +1,47 @@
-use bevy::prelude::{App, Component, Plugin};
+use bevy::prelude::{App, Component, CoreStage, Entity, Plugin};
 
 use super::{
     ai::{
-        aggro::monster_aggro_system, monster_attack::monster_fight_system,
-        receive_damages::monster_hit_system,
+        aggro::monster_aggro_system,
+        monster_attack::monster_fight_system,
+        monster_ranged_attack::monster_ranged_attack_system,
+        receive_damages::{kill_monster_system, monster_hit_system, player_hit_system},
+    },
+    block::block_system,
+    combat_events::{
+        BlockEvent, DamageResultEvent, DodgeEvent, DrawBowEvent, ExplosionEvent,
+        FireProjectileEvent, HitMonsterEvent, HitPlayerEvent, KillMonsterEvent, LockTargetEvent,
+        ReleaseBowEvent, ReleaseTargetEvent,
+    },
+    dodge::{dodge_system, dodge_timers_system},
+    target::{
+        face_target_system, highlight_target_system, lock_target_system, release_target_system,
+    },
+    weapons::{
+        explosive::explosion_system,
+        melee::swing::swing_hit_system,
+        range::{
+            bow::{
+                draw_bow_system, fire_projectile_system, release_bow_system, tick_bow_draw_system,
+            },
+            projectile::{projectile_collision_system, projectile_lifetime_system, ProjectileType},
+        },
     },
-    combat_events::{FireProjectileEvent, HitMonsterEvent, KillMonsterEvent},
-    weapons::range::{bow::fire_projectile_system, projectile::projectile_collision_system},
 };
 
-#[derive(Component, Default)]
-pub struct Projectile;
+#[derive(Component)]
+pub struct Projectile {
+    pub projectile_type: ProjectileType,
+    /// The creature which fired the projectile, never hit by it
+    pub shooter: Entity,
+    /// Fired by the player, only hitting monsters unless friendly fire is enabled
+    pub from_player: bool,
+    pub damage: f32,
+    /// Creatures already hit, each one is hit once
+    pub hit: Vec<Entity>,
+    /// Stuck in or broken on the ground, the projectile does not hit anymore
+    pub landed: bool,
+}
 
 #[derive(Default)]
 pub struct MonstersKilled {
@@ -23,12 +54,37 @@ impl Plugin for CombatPlugin {
         app.insert_resource(MonstersKilled { count: 0 })
             .add_event::<FireProjectileEvent>()
             .add_event::<HitMonsterEvent>()
+            .add_event::<HitPlayerEvent>()
+            .add_event::<DamageResultEvent>()
             .add_event::<KillMonsterEvent>()
+            .add_event::<ExplosionEvent>()
+            .add_event::<DrawBowEvent>()
+            .add_event::<ReleaseBowEvent>()
+            .add_event::<DodgeEvent>()
+            .add_event::<BlockEvent>()
+            .add_event::<LockTargetEvent>()
+            .add_event::<ReleaseTargetEvent>()
             .add_system(fire_projectile_system)
             .add_system(monster_hit_system)
+            .add_system_to_stage(CoreStage::PostUpdate, kill_monster_system)
+            .add_system(player_hit_system)
             .add_system(monster_aggro_system)
             .add_system(monster_fight_system)
+            .add_system(monster_ranged_attack_system)
             .add_system(fire_projectile_system)
-            .add_system(projectile_collision_system);
+            .add_system(projectile_collision_system)
+            .add_system(projectile_lifetime_system)
+            .add_system(explosion_system)
+            .add_system(draw_bow_system)
+            .add_system(tick_bow_draw_system)
+            .add_system(release_bow_system)
+            .add_system(swing_hit_system)
+            .add_system(dodge_system)
+            .add_system(dodge_timers_system)
+            .add_system(block_system)
+            .add_system(lock_target_system)
+            .add_system(release_target_system)
+            .add_system(face_target_system)
+            .add_system(highlight_target_system);
     }
 }
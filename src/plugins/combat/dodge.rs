@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+use crate::{
+    plugins::{
+        combat::combat_events::DodgeEvent,
+        creature::{
+            creature_plugin::Creature,
+            systems::{stamina::Stamina, stats::Staggered},
+        },
+    },
+    DODGE_COOLDOWN, DODGE_IFRAMES_TIME, DODGE_SPEED, DODGE_STAMINA_COST, DODGE_TIME,
+};
+
+/// Rolling creature, its burst velocity is kept until the timer finishes
+#[derive(Component)]
+pub struct Dodging(pub Timer);
+
+/// Hits received by the creature are ignored until the timer finishes
+#[derive(Component)]
+pub struct Invulnerable(pub Timer);
+
+/// The creature cannot dodge again until the timer finishes
+#[derive(Component)]
+pub struct DodgeCooldown(pub Timer);
+
+/**
+ * Roll the creature along the requested direction, granting a few invulnerability frames
+ *
+ * Staggered, rolling and exhausted creatures cannot dodge, nor can they before the cooldown ends
+ */
+pub fn dodge_system(
+    mut commands: Commands,
+    mut creature_q: Query<
+        (&mut Velocity, &mut Stamina),
+        (
+            With<Creature>,
+            Without<Staggered>,
+            Without<Dodging>,
+            Without<DodgeCooldown>,
+        ),
+    >,
+    mut ev_dodge: EventReader<DodgeEvent>,
+) {
+    for ev in ev_dodge.iter() {
+        let (mut velocity, mut stamina) = match creature_q.get_mut(ev.0) {
+            Ok(creature) => creature,
+            Err(_) => continue,
+        };
+
+        let direction = (ev.1 * Vec3::new(1., 0., 1.)).normalize_or_zero();
+        if direction == Vec3::ZERO {
+            continue;
+        }
+
+        if !stamina.try_consume(DODGE_STAMINA_COST) {
+            println!("Too exhausted to dodge");
+            continue;
+        }
+
+        velocity.linvel = Vec3::new(0., velocity.linvel.y, 0.) + direction * DODGE_SPEED;
+
+        commands
+            .entity(ev.0)
+            .insert(Dodging(Timer::from_seconds(DODGE_TIME, false)))
+            .insert(Invulnerable(Timer::from_seconds(DODGE_IFRAMES_TIME, false)))
+            .insert(DodgeCooldown(Timer::from_seconds(DODGE_COOLDOWN, false)));
+    }
+}
+
+pub fn dodge_timers_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut dodging_q: Query<(Entity, &mut Dodging)>,
+    mut invulnerable_q: Query<(Entity, &mut Invulnerable)>,
+    mut cooldown_q: Query<(Entity, &mut DodgeCooldown)>,
+) {
+    for (entity, mut dodging) in dodging_q.iter_mut() {
+        if dodging.0.tick(time.delta()).finished() {
+            commands.entity(entity).remove::<Dodging>();
+        }
+    }
+
+    for (entity, mut invulnerable) in invulnerable_q.iter_mut() {
+        if invulnerable.0.tick(time.delta()).finished() {
+            commands.entity(entity).remove::<Invulnerable>();
+        }
+    }
+
+    for (entity, mut cooldown) in cooldown_q.iter_mut() {
+        if cooldown.0.tick(time.delta()).finished() {
+            commands.entity(entity).remove::<DodgeCooldown>();
+        }
+    }
+}
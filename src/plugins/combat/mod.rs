@@ -1,4 +1,7 @@
 pub mod ai;
+pub mod block;
 pub mod combat_events;
 pub mod combat_plugin;
+pub mod dodge;
+pub mod target;
 pub mod weapons;
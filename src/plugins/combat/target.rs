@@ -0,0 +1,148 @@
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+
+use crate::{
+    plugins::{
+        combat::combat_events::{LockTargetEvent, ReleaseTargetEvent},
+        creature::{
+            creature_plugin::{Creature, Monster, Player},
+            systems::visual::BodyMesh,
+        },
+    },
+    TARGET_HIGHLIGHT_COLOR, TARGET_LOCK_RANGE,
+};
+
+/// Monster locked by the creature, which keeps facing it
+#[derive(Component)]
+pub struct Target(pub Entity);
+
+/**
+ * Lock the requested monster, or the nearest monster in range,
+ * locking again without monster cycles to the next nearest one
+ */
+pub fn lock_target_system(
+    mut commands: Commands,
+    creature_q: Query<(&Transform, Option<&Target>), With<Creature>>,
+    monster_q: Query<(Entity, &Transform), With<Monster>>,
+    mut ev_lock_target: EventReader<LockTargetEvent>,
+) {
+    for ev in ev_lock_target.iter() {
+        let (transform, target) = match creature_q.get(ev.0) {
+            Ok(creature) => creature,
+            Err(_) => continue,
+        };
+
+        let locked = match ev.1 {
+            Some(monster) if monster_q.contains(monster) => Some(monster),
+            Some(_) => continue,
+            None => {
+                let mut in_range: Vec<(Entity, f32)> = monster_q
+                    .iter()
+                    .map(|(monster, monster_transform)| {
+                        (
+                            monster,
+                            monster_transform
+                                .translation
+                                .distance(transform.translation),
+                        )
+                    })
+                    .filter(|(_, distance)| *distance <= TARGET_LOCK_RANGE)
+                    .collect();
+                in_range.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+                // Next monster after the current target, the nearest one otherwise
+                let current = target.and_then(|target| {
+                    in_range
+                        .iter()
+                        .position(|(monster, _)| *monster == target.0)
+                });
+                let next = match current {
+                    Some(index) => (index + 1) % in_range.len(),
+                    None => 0,
+                };
+
+                in_range.get(next).map(|(monster, _)| *monster)
+            }
+        };
+
+        match locked {
+            Some(monster) => commands.entity(ev.0).insert(Target(monster)),
+            None => commands.entity(ev.0).remove::<Target>(),
+        };
+    }
+}
+
+pub fn release_target_system(
+    mut commands: Commands,
+    mut ev_release_target: EventReader<ReleaseTargetEvent>,
+) {
+    for ev in ev_release_target.iter() {
+        commands.entity(ev.0).remove::<Target>();
+    }
+}
+
+/**
+ * Turn the player toward its target, the lock is lost when the target is dead or out of range
+ */
+pub fn face_target_system(
+    mut commands: Commands,
+    mut player_q: Query<(Entity, &mut Transform, &Target), With<Player>>,
+    monster_q: Query<&Transform, (With<Monster>, Without<Player>)>,
+) {
+    for (player, mut transform, target) in player_q.iter_mut() {
+        let target_position = match monster_q.get(target.0) {
+            Ok(monster_transform) => monster_transform.translation,
+            Err(_) => {
+                commands.entity(player).remove::<Target>();
+                continue;
+            }
+        };
+
+        if target_position.distance(transform.translation) > TARGET_LOCK_RANGE * 1.5 {
+            commands.entity(player).remove::<Target>();
+            continue;
+        }
+
+        // Same orientation as the mouse aiming, the items point along the x axis
+        let looking_at = Vec3::new(
+            target_position.x,
+            transform.translation.y,
+            target_position.z,
+        );
+        let mut facing = Transform::from_translation(transform.translation);
+        facing.look_at(looking_at, Vec3::Y * 2.);
+        facing.rotate_y(PI / 2.);
+        transform.rotation = facing.rotation;
+    }
+}
+
+/**
+ * Light up the body of the targeted monster
+ */
+pub fn highlight_target_system(
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    target_q: Query<&Target, With<Player>>,
+    body_q: Query<(&Parent, &Handle<StandardMaterial>), With<BodyMesh>>,
+) {
+    let target = target_q.get_single().ok().map(|target| target.0);
+
+    for (parent, handle) in body_q.iter() {
+        let emissive = if Some(parent.get()) == target {
+            TARGET_HIGHLIGHT_COLOR
+        } else {
+            Color::BLACK
+        };
+
+        let highlighted = match materials.get(handle) {
+            Some(material) => material.emissive == emissive,
+            None => continue,
+        };
+
+        if !highlighted {
+            if let Some(material) = materials.get_mut(handle) {
+                material.emissive = emissive;
+            }
+        }
+    }
+}
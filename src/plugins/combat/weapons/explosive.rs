@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+
+use crate::{
+    plugins::{
+        combat::combat_events::{ExplosionEvent, Hit, HitMonsterEvent},
+        creature::{
+            creature_plugin::Monster,
+            systems::{hit_zones::HitZoneType, status_effects::StatusEffectType},
+        },
+    },
+    EXPLOSION_KNOCKBACK, EXPLOSION_STAGGER,
+};
+
+/**
+ * Hit every monster caught in an explosion, pushing it away from the center and burning it
+ */
+pub fn explosion_system(
+    monsters_q: Query<(Entity, &Transform), With<Monster>>,
+    mut ev_explosion: EventReader<ExplosionEvent>,
+    mut ev_hit_monster: EventWriter<HitMonsterEvent>,
+) {
+    for ev in ev_explosion.iter() {
+        let (center, radius, damage) = (ev.0, ev.1, ev.2);
+
+        for (entity, transform) in monsters_q.iter() {
+            if transform.translation.distance(center) > radius {
+                continue;
+            }
+
+            ev_hit_monster.send(HitMonsterEvent(
+                entity,
+                Hit {
+                    origin: center,
+                    damage,
+                    zone: HitZoneType::Body,
+                    knockback: EXPLOSION_KNOCKBACK,
+                    stagger: EXPLOSION_STAGGER,
+                    status: Some(StatusEffectType::Burn),
+                },
+            ));
+        }
+    }
+}
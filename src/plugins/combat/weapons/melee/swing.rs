@@ -0,0 +1,127 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+use crate::plugins::{
+    combat::combat_events::{Hit, HitMonsterEvent},
+    creature::{
+        creature_plugin::{Creature, Monster},
+        systems::{
+            hit_zones::{HitZone, HitZoneType},
+            sensors::WeaponHitbox,
+            stats::{Staggered, Stats},
+            status_effects::StatusEffects,
+        },
+    },
+    farming::{farming_events::HarvestResourceEvent, resource_nodes::ResourceNode},
+    items::items_plugin::{AnimateVisualItem, AnimationTimer, Item, Swing},
+};
+
+/// # swing_hit_system
+/// Apply the hits of the swinging items during the active frames of their animation
+///
+/// Weapons hit the monsters and tools harvest the resource nodes inside the hitbox
+/// of the creature holding the item, each entity is hit once per swing
+///
+/// The damages and knockback of the item are scaled by the attack of the swing,
+/// a monster is hit on its zone closest to the center of the hitbox,
+/// staggered creatures do not hit and weakened ones deal less damages
+///
+pub fn swing_hit_system(
+    rapier_context: Res<RapierContext>,
+    mut swinging_q: Query<(&Item, &AnimationTimer, &mut Swing, &Parent), With<AnimateVisualItem>>,
+    creature_q: Query<(&Transform, &Stats, &StatusEffects), (With<Creature>, Without<Staggered>)>,
+    hitbox_q: Query<(Entity, &Parent, &GlobalTransform), With<WeaponHitbox>>,
+    zone_q: Query<(&HitZone, &Parent, &GlobalTransform)>,
+    monster_q: Query<Entity, With<Monster>>,
+    node_q: Query<Entity, With<ResourceNode>>,
+    mut ev_hit_monster: EventWriter<HitMonsterEvent>,
+    mut ev_harvest_resource: EventWriter<HarvestResourceEvent>,
+) {
+    for (item, animation_timer, mut swing, parent) in swinging_q.iter_mut() {
+        let (start, end) = item.item_type.active_frames();
+        let progress = animation_timer.0.percent();
+        if progress < start || progress > end {
+            continue;
+        }
+
+        let creature = parent.get();
+        let (transform, stats, status_effects) = match creature_q.get(creature) {
+            Ok(creature) => creature,
+            Err(_) => continue,
+        };
+
+        let (collider, hitbox_center) = match hitbox_q
+            .iter()
+            .find(|(_, hitbox_parent, _)| hitbox_parent.get() == creature)
+        {
+            Some((hitbox, _, global_transform)) => (hitbox, global_transform.translation()),
+            None => continue,
+        };
+
+        // Zone of each monster closest to the center of the hitbox
+        let mut zones_hit: Vec<(Entity, HitZoneType, f32)> = Vec::new();
+
+        /* Iterate through all the intersection pairs involving a specific collider. */
+        for (collider1, collider2, intersecting) in rapier_context.intersections_with(collider) {
+            if !intersecting {
+                continue;
+            }
+
+            let other = if collider1 == collider {
+                collider2
+            } else {
+                collider1
+            };
+
+            if swing.hit.contains(&other) {
+                continue;
+            }
+
+            // Weapons hit monsters
+            if item.item_type.is_weapon() {
+                if let Ok((zone, zone_parent, zone_transform)) = zone_q.get(other) {
+                    let victim = zone_parent.get();
+                    let distance = zone_transform.translation().distance(hitbox_center);
+
+                    if swing.hit.contains(&victim) || !monster_q.contains(victim) {
+                        continue;
+                    }
+
+                    match zones_hit
+                        .iter_mut()
+                        .find(|(entity, _, _)| *entity == victim)
+                    {
+                        Some(closest) if closest.2 <= distance => (),
+                        Some(closest) => *closest = (victim, zone.0, distance),
+                        None => zones_hit.push((victim, zone.0, distance)),
+                    }
+                }
+            }
+
+            // Tools harvest resource nodes
+            if item.item_type.is_tool() {
+                if let Ok(node) = node_q.get(other) {
+                    swing.hit.push(node);
+                    ev_harvest_resource.send(HarvestResourceEvent(creature, node));
+                }
+            }
+        }
+
+        let attack = item.item_type.attack(swing.attack);
+
+        for (victim, zone, _) in zones_hit {
+            swing.hit.push(victim);
+            ev_hit_monster.send(HitMonsterEvent(
+                victim,
+                Hit {
+                    origin: transform.translation,
+                    damage: stats.atk * attack.damage * status_effects.atk_multiplier(),
+                    zone,
+                    knockback: item.item_type.knockback() * attack.knockback,
+                    stagger: item.item_type.stagger_time(),
+                    status: item.item_type.on_hit_status(),
+                },
+            ));
+        }
+    }
+}
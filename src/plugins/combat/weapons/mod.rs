@@ -1 +1,3 @@
+pub mod explosive;
+pub mod melee;
 pub mod range;
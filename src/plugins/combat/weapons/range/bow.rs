@@ -1,28 +1,198 @@
 use bevy::prelude::*;
 use bevy_rapier3d::prelude::*;
 
-use crate::plugins::{
-    combat::{combat_events::FireProjectileEvent, combat_plugin::Projectile},
-    creature::creature_plugin::Player,
+use crate::{
+    plugins::{
+        combat::{
+            combat_events::{DrawBowEvent, FireProjectileEvent, ReleaseBowEvent},
+            combat_plugin::Projectile,
+            weapons::range::projectile::{ProjectileLifetime, ProjectileType},
+        },
+        creature::{
+            creature_plugin::{Creature, Player},
+            systems::{stamina::Stamina, stats::Staggered, status_effects::StatusEffects},
+        },
+        items::items_plugin::{Belt, EquippedItem, Inventory, Item, Quantity},
+    },
+    BOW_MAX_DRAW_TIME, BOW_MIN_DRAW_POWER,
 };
 
+/// Draw state of a ranged item, the shot power grows until the timer is finished
+#[derive(Component)]
+pub struct BowDraw(pub Timer);
+
+impl BowDraw {
+    pub fn power(&self) -> f32 {
+        BOW_MIN_DRAW_POWER + (1. - BOW_MIN_DRAW_POWER) * self.0.percent()
+    }
+}
+
+/**
+ * Start drawing the equipped ranged item if it is ready and ammunition is left in the inventory,
+ * exhausted creatures cannot draw
+ */
+pub fn draw_bow_system(
+    mut commands: Commands,
+    creature_q: Query<(&EquippedItem, &Inventory, &Stamina), (With<Creature>, Without<Staggered>)>,
+    item_q: Query<&Item>,
+    mut ev_draw_bow: EventReader<DrawBowEvent>,
+) {
+    for ev in ev_draw_bow.iter() {
+        let (equipped_item, inventory, stamina) = match creature_q.get(ev.0) {
+            Ok(creature) => creature,
+            Err(_) => continue,
+        };
+
+        let (bow_entity, bow) = match equipped_item.0.map(|entity| (entity, item_q.get(entity))) {
+            Some((entity, Ok(item))) if item.item_type.is_ranged() => (entity, item),
+            _ => continue,
+        };
+
+        if !bow.is_ready() || stamina.exhausted {
+            continue;
+        }
+
+        let has_ammo = inventory.0.iter().any(|entity| match item_q.get(*entity) {
+            Ok(item) => Some(item.item_type) == bow.item_type.ammo(),
+            Err(_) => false,
+        });
+
+        if !has_ammo {
+            println!("No ammunition left for {}", bow.item_type.name());
+            continue;
+        }
+
+        commands
+            .entity(bow_entity)
+            .insert(BowDraw(Timer::from_seconds(BOW_MAX_DRAW_TIME, false)));
+    }
+}
+
+pub fn tick_bow_draw_system(time: Res<Time>, mut draw_q: Query<&mut BowDraw>) {
+    for mut draw in draw_q.iter_mut() {
+        draw.0.tick(time.delta());
+    }
+}
+
+/**
+ * Consume one ammunition of the inventory and some stamina,
+ * then fire a projectile with the current draw power
+ */
+pub fn release_bow_system(
+    mut commands: Commands,
+    mut creature_q: Query<
+        (
+            &EquippedItem,
+            &mut Inventory,
+            &mut Belt,
+            &mut Stamina,
+            Option<&Staggered>,
+        ),
+        With<Creature>,
+    >,
+    mut bow_q: Query<(&mut Item, &BowDraw)>,
+    mut ammo_q: Query<(&Item, &mut Quantity), Without<BowDraw>>,
+    mut ev_release_bow: EventReader<ReleaseBowEvent>,
+    mut ev_fire_projectile: EventWriter<FireProjectileEvent>,
+) {
+    for ev in ev_release_bow.iter() {
+        let (equipped_item, mut inventory, mut belt, mut stamina, staggered) =
+            match creature_q.get_mut(ev.0) {
+                Ok(creature) => creature,
+                Err(_) => continue,
+            };
+
+        let bow_entity = match equipped_item.0 {
+            Some(entity) => entity,
+            None => continue,
+        };
+
+        let (mut bow, draw) = match bow_q.get_mut(bow_entity) {
+            Ok(bow) => bow,
+            Err(_) => continue,
+        };
+
+        let power = draw.power();
+        commands.entity(bow_entity).remove::<BowDraw>();
+
+        // The shot is lost when the creature is staggered while drawing
+        let target = match (ev.1, staggered) {
+            (Some(target), None) => target,
+            _ => continue,
+        };
+
+        let ammo_entity = match inventory
+            .0
+            .iter()
+            .find(|entity| match ammo_q.get(**entity) {
+                Ok((item, _)) => Some(item.item_type) == bow.item_type.ammo(),
+                Err(_) => false,
+            }) {
+            Some(entity) => *entity,
+            None => continue,
+        };
+
+        if !stamina.try_consume(bow.item_type.stamina_cost()) {
+            println!("Too exhausted to shoot");
+            continue;
+        }
+
+        // Take one ammunition from the stack, remove the stack once empty
+        if let Ok((_, mut quantity)) = ammo_q.get_mut(ammo_entity) {
+            quantity.0 = quantity.0.saturating_sub(1);
+
+            if quantity.0 == 0 {
+                inventory.0.retain(|item| *item != ammo_entity);
+                belt.0.retain(|item| *item != ammo_entity);
+                commands.entity(ammo_entity).despawn_recursive();
+            }
+        }
+
+        bow.activate();
+        ev_fire_projectile.send(FireProjectileEvent(
+            ev.0,
+            target,
+            power,
+            ProjectileType::Arrow,
+        ));
+    }
+}
+
+/**
+ * Spawn the projectiles, aimed at the height of the shooter and lifted to compensate gravity,
+ * their damages are weakened by the status effects of the shooter
+ */
 pub fn fire_projectile_system(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    rapier_config: Res<RapierConfiguration>,
     mut ev_fire_projectile: EventReader<FireProjectileEvent>,
-    q_player: Query<&Transform, With<Player>>,
+    q_shooter: Query<(&Transform, &StatusEffects, Option<&Player>), With<Creature>>,
 ) {
     for ev in ev_fire_projectile.iter() {
-        let player_transform = q_player.get_single().expect("No Player found");
+        let (shooter_transform, status_effects, player) = match q_shooter.get(ev.0) {
+            Ok(shooter) => shooter,
+            Err(_) => continue,
+        };
+        let projectile_type = ev.3;
+
         let mut projectile_transform = Transform {
-            translation: player_transform.translation,
+            translation: shooter_transform.translation,
             ..default()
         };
 
-        let looking_at = Vec3::new(ev.0.x, 2., ev.0.z);
+        let looking_at = Vec3::new(ev.1.x, shooter_transform.translation.y, ev.1.z);
         projectile_transform.look_at(looking_at, Vec3::Y);
 
+        // Vertical speed needed to land at the target distance
+        let speed = projectile_type.speed() * ev.2;
+        let flight_time = projectile_transform.translation.distance(looking_at) / speed;
+        let gravity = rapier_config.gravity.y.abs() * projectile_type.gravity_scale();
+        let linvel = projectile_transform.forward() * speed + Vec3::Y * gravity * flight_time / 2.;
+
+        let size = projectile_type.size();
+
         commands
             .spawn_bundle(SpatialBundle {
                 transform: Transform::from_xyz(0., 0., 0.),
@@ -31,27 +201,33 @@ pub fn fire_projectile_system(
             .insert(RigidBody::Dynamic)
             .insert_bundle(TransformBundle::from_transform(projectile_transform))
             .insert(LockedAxes::ROTATION_LOCKED)
-            .insert(Collider::cuboid(0.2 / 2., 0.2 / 2., 1.2 / 2.))
-            .insert(Velocity::default())
-            .insert(Damping {
-                linear_damping: 1.,
-                ..default()
+            .insert(Collider::cuboid(size.x / 2., size.y / 2., size.z / 2.))
+            .insert(Sensor)
+            .insert(GravityScale(projectile_type.gravity_scale()))
+            .insert(Velocity {
+                linvel,
+                angvel: Vec3::ZERO,
             })
-            .insert(Restitution::coefficient(50.))
-            .insert(Dominance::group(2))
             .insert(ActiveEvents::COLLISION_EVENTS) // Enable events to detect projectile events
-            .insert(Projectile)
-            .insert(ExternalImpulse {
-                impulse: projectile_transform.forward().normalize() * 10.,
-                torque_impulse: Vec3::splat(0.),
+            .insert(Projectile {
+                projectile_type,
+                shooter: ev.0,
+                from_player: player.is_some(),
+                damage: projectile_type.damage() * ev.2 * status_effects.atk_multiplier(),
+                hit: Vec::new(),
+                landed: false,
             })
-            .insert(CollisionGroups::new(Group::GROUP_4, Group::GROUP_2))
+            .insert(ProjectileLifetime(Timer::from_seconds(
+                projectile_type.lifetime(),
+                false,
+            )))
+            .insert(projectile_type.collision_groups())
             //
             // Add Sprite
             .with_children(|parent| {
                 parent.spawn_bundle(PbrBundle {
-                    mesh: meshes.add(Mesh::from(shape::Box::new(0.2, 0.2, 1.2))),
-                    material: materials.add(Color::BLUE.into()),
+                    mesh: meshes.add(Mesh::from(shape::Box::new(size.x, size.y, size.z))),
+                    material: materials.add(projectile_type.color().into()),
                     transform: Transform::from_xyz(0., 0., 0.),
                     ..default()
                 });
@@ -1,46 +1,233 @@
 use bevy::prelude::*;
 use bevy_rapier3d::prelude::*;
+use serde::Deserialize;
 
-use crate::plugins::combat::{combat_events::HitMonsterEvent, combat_plugin::Projectile};
+use crate::{
+    plugins::{
+        combat::{
+            combat_events::{Hit, HitMonsterEvent, HitPlayerEvent},
+            combat_plugin::Projectile,
+        },
+        creature::{
+            creature_plugin::{Creature, Player},
+            systems::{hit_zones::HitZone, status_effects::StatusEffectType},
+        },
+    },
+    ARROW_DAMAGE, FRIENDLY_FIRE, HIT_ZONE_GROUP, PROJECTILE_GROUP, RESOURCE_NODE_GROUP,
+    STUCK_PROJECTILE_TIME, TERRAIN_GROUP,
+};
 
+// ------------------
+//
+// ProjectileType
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+pub enum ProjectileType {
+    Arrow,
+    // Thrown by monsters
+    Rock,
+}
+
+impl ProjectileType {
+    pub fn damage(&self) -> f32 {
+        match self {
+            ProjectileType::Arrow => ARROW_DAMAGE,
+            ProjectileType::Rock => 12.,
+        }
+    }
+
+    /// Launch speed in meters per second at full power
+    pub fn speed(&self) -> f32 {
+        match self {
+            ProjectileType::Arrow => 30.,
+            ProjectileType::Rock => 14.,
+        }
+    }
+
+    /// Knockback speed of a hit, for a victim of KNOCKBACK_REFERENCE_MASS
+    pub fn knockback(&self) -> f32 {
+        match self {
+            ProjectileType::Arrow => 4.,
+            ProjectileType::Rock => 6.,
+        }
+    }
+
+    /// Seconds during which the victim of a hit can neither move nor attack
+    pub fn stagger_time(&self) -> f32 {
+        match self {
+            ProjectileType::Arrow => 0.3,
+            ProjectileType::Rock => 0.5,
+        }
+    }
+
+    /// Seconds before a flying projectile disappears
+    pub fn lifetime(&self) -> f32 {
+        match self {
+            ProjectileType::Arrow => 4.,
+            ProjectileType::Rock => 3.,
+        }
+    }
+
+    /// Part of the world gravity applied to the projectile, 0 flies straight
+    pub fn gravity_scale(&self) -> f32 {
+        match self {
+            ProjectileType::Arrow => 0.3,
+            ProjectileType::Rock => 1.,
+        }
+    }
+
+    /// Number of creatures the projectile goes through before stopping
+    pub fn pierce(&self) -> u32 {
+        match self {
+            ProjectileType::Arrow => 1,
+            ProjectileType::Rock => 0,
+        }
+    }
+
+    /// Projectiles sticking in the ground and walls instead of breaking
+    pub fn sticks(&self) -> bool {
+        match self {
+            ProjectileType::Arrow => true,
+            ProjectileType::Rock => false,
+        }
+    }
+
+    /// Status effect applied to the creatures hit
+    pub fn on_hit_status(&self) -> Option<StatusEffectType> {
+        match self {
+            ProjectileType::Arrow => Some(StatusEffectType::Bleed),
+            ProjectileType::Rock => None,
+        }
+    }
+
+    pub fn size(&self) -> Vec3 {
+        match self {
+            ProjectileType::Arrow => Vec3::new(0.2, 0.2, 1.2),
+            ProjectileType::Rock => Vec3::new(0.4, 0.4, 0.4),
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            ProjectileType::Arrow => Color::BLUE,
+            ProjectileType::Rock => Color::DARK_GRAY,
+        }
+    }
+
+    /// Projectiles hit the creatures through their hit zones
+    pub fn collision_groups(&self) -> CollisionGroups {
+        CollisionGroups::new(
+            PROJECTILE_GROUP,
+            HIT_ZONE_GROUP | TERRAIN_GROUP | RESOURCE_NODE_GROUP,
+        )
+    }
+}
+
+/// Time left before the projectile is despawned
+#[derive(Component)]
+pub struct ProjectileLifetime(pub Timer);
+
+pub fn projectile_lifetime_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut projectile_q: Query<(Entity, &mut ProjectileLifetime)>,
+) {
+    for (entity, mut lifetime) in projectile_q.iter_mut() {
+        if lifetime.0.tick(time.delta()).finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+/**
+ * Damage the creatures crossed by projectiles, the projectile stops once its pierce count is
+ * exceeded or when it reaches the ground, a wall or a resource node
+ */
 pub fn projectile_collision_system(
     mut commands: Commands,
     mut collision_events: EventReader<CollisionEvent>,
     mut ev_monster_hit: EventWriter<HitMonsterEvent>,
-    q_projectile: Query<&Transform, With<Projectile>>,
+    mut ev_player_hit: EventWriter<HitPlayerEvent>,
+    mut q_projectile: Query<(&Transform, &mut Projectile, &mut ProjectileLifetime)>,
+    q_zone: Query<(&HitZone, &Parent)>,
+    q_creature: Query<Option<&Player>, With<Creature>>,
 ) {
     for collision_event in collision_events.iter() {
-        match collision_event {
-            CollisionEvent::Started(entity_1_ref, entity_2_ref, _) => {
-                let entity_1 = commands.entity(*entity_1_ref).id();
-                let entity_2 = commands.entity(*entity_2_ref).id();
-
-                // Projectile should react only with monsters (see CollisionGroups) so other entity is always a monster
-                let (projectile_entity, monster_entity) = match q_projectile.get(entity_1) {
-                    Ok(_) => (entity_1, entity_2),
-                    Err(_) => match q_projectile.get(entity_2) {
-                        Ok(_) => (entity_2, entity_1),
-                        Err(_) => continue, // If no projectile, continue events iteration
-                    },
+        let (entity_1, entity_2) = match collision_event {
+            CollisionEvent::Started(entity_1, entity_2, _) => (*entity_1, *entity_2),
+            CollisionEvent::Stopped(_, _, _) => continue,
+        };
+
+        let (projectile_entity, other) = if q_projectile.contains(entity_1) {
+            (entity_1, entity_2)
+        } else if q_projectile.contains(entity_2) {
+            (entity_2, entity_1)
+        } else {
+            continue; // If no projectile, continue events iteration
+        };
+
+        let (projectile_transform, mut projectile, mut lifetime) =
+            match q_projectile.get_mut(projectile_entity) {
+                Ok(projectile) => projectile,
+                Err(_) => continue,
+            };
+
+        if projectile.landed {
+            continue;
+        }
+
+        match q_zone.get(other) {
+            Ok((zone, zone_parent)) => {
+                let victim = zone_parent.get();
+                if victim == projectile.shooter || projectile.hit.contains(&victim) {
+                    continue;
+                }
+
+                let is_player = match q_creature.get(victim) {
+                    Ok(player) => player.is_some(),
+                    Err(_) => continue,
                 };
 
-                let projectile_transform =
-                    q_projectile.get(projectile_entity).expect("No projectile");
+                // Projectiles only hit the other faction, unless friendly fire is enabled
+                if !FRIENDLY_FIRE && is_player == projectile.from_player {
+                    continue;
+                }
 
-                // println!(
-                //     "Projectile rotation {:?}",
-                //     projectile_transform.rotation.to_axis_angle()
-                // );
+                projectile.hit.push(victim);
 
-                ev_monster_hit.send(HitMonsterEvent(
-                    monster_entity,
-                    projectile_transform.rotation.to_axis_angle().1,
-                ));
+                // Victims are pushed along the flight direction
+                let hit = Hit {
+                    origin: projectile_transform.translation - projectile_transform.forward(),
+                    damage: projectile.damage,
+                    zone: zone.0,
+                    knockback: projectile.projectile_type.knockback(),
+                    stagger: projectile.projectile_type.stagger_time(),
+                    status: projectile.projectile_type.on_hit_status(),
+                };
+
+                if is_player {
+                    ev_player_hit.send(HitPlayerEvent(victim, hit));
+                } else {
+                    ev_monster_hit.send(HitMonsterEvent(victim, hit));
+                }
 
-                commands.entity(projectile_entity).despawn_recursive();
+                if projectile.hit.len() as u32 > projectile.projectile_type.pierce() {
+                    projectile.landed = true;
+                    commands.entity(projectile_entity).despawn_recursive();
+                }
             }
-            CollisionEvent::Stopped(_, _, _) => {
-                continue;
+            // Ground, walls and resource nodes
+            Err(_) => {
+                projectile.landed = true;
+
+                if projectile.projectile_type.sticks() {
+                    commands
+                        .entity(projectile_entity)
+                        .remove::<RigidBody>()
+                        .remove::<Collider>();
+                    lifetime.0 = Timer::from_seconds(STUCK_PROJECTILE_TIME, false);
+                } else {
+                    commands.entity(projectile_entity).despawn_recursive();
+                }
             }
         }
     }
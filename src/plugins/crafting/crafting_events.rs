@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+
+/// Start crafting a recipe
+/// ### Param1 - {Entity} - The creature crafting the recipe
+/// ### Param2 - {usize} - The recipe index in the RecipeBook
+pub struct CraftItemEvent(pub Entity, pub usize);
+
+/// A recipe has been crafted and its outputs stored in the creature inventory
+/// ### Param1 - {Entity} - The creature who crafted the recipe
+/// ### Param2 - {usize} - The recipe index in the RecipeBook
+pub struct ItemCraftedEvent(pub Entity, pub usize);
+
+/// Open or close the crafting menu
+pub struct ToggleCraftingMenuEvent;
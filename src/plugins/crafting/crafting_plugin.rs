@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+use super::{
+    crafting_events::{CraftItemEvent, ItemCraftedEvent, ToggleCraftingMenuEvent},
+    recipes::RecipeBook,
+    systems::{
+        craft::{crafting_progress_system, start_crafting_system},
+        stations::dev_init_crafting_stations_system,
+        ui::{
+            init_crafting_menu_system, recipe_button_system, toggle_crafting_menu_system,
+            update_crafting_menu_system,
+        },
+    },
+};
+
+/// A creature currently crafting a recipe
+#[derive(Component)]
+pub struct Crafting {
+    pub recipe: usize,
+    pub timer: Timer,
+}
+
+pub struct CraftingPlugin;
+impl Plugin for CraftingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RecipeBook>()
+            .add_event::<CraftItemEvent>()
+            .add_event::<ItemCraftedEvent>()
+            .add_event::<ToggleCraftingMenuEvent>()
+            .add_startup_system(dev_init_crafting_stations_system)
+            .add_startup_system(init_crafting_menu_system)
+            .add_system(start_crafting_system)
+            .add_system(crafting_progress_system)
+            .add_system(toggle_crafting_menu_system)
+            .add_system(recipe_button_system)
+            .add_system(update_crafting_menu_system);
+    }
+}
@@ -0,0 +1,4 @@
+pub mod crafting_events;
+pub mod crafting_plugin;
+pub mod recipes;
+pub mod systems;
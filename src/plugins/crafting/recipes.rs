@@ -0,0 +1,262 @@
+use bevy::prelude::*;
+
+use crate::{
+    plugins::items::{items_plugin::ItemType, systems::inventory::InventoryStack},
+    utils::error::ErrorMessage,
+    CRAFTING_STATION_RANGE,
+};
+
+// ------------------
+//
+// Crafting stations
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StationType {
+    Workbench,
+    Furnace,
+}
+
+impl StationType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            StationType::Workbench => "Workbench",
+            StationType::Furnace => "Furnace",
+        }
+    }
+
+    pub fn size(&self) -> Vec3 {
+        match self {
+            StationType::Workbench => Vec3::new(2., 1., 1.),
+            StationType::Furnace => Vec3::new(1.5, 1.5, 1.5),
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            StationType::Workbench => Color::rgb(0.6, 0.4, 0.2),
+            StationType::Furnace => Color::DARK_GRAY,
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct CraftingStation(pub StationType);
+
+// ------------------
+//
+// Recipes
+pub struct Recipe {
+    pub name: &'static str,
+    pub inputs: Vec<(ItemType, u32)>,
+    pub outputs: Vec<(ItemType, u32)>,
+    pub station: Option<StationType>,
+    pub tool: Option<ItemType>,
+    pub craft_time: f32,
+}
+
+impl Recipe {
+    /// Pick the inventory stacks and quantities consumed by this recipe
+    ///
+    /// Fails if an input is missing, if the required tool is not in the inventory
+    /// or if the required station is out of range.
+    /// The equipped item counts as a tool but is never consumed
+    pub fn collect_inputs(
+        &self,
+        stacks: &[InventoryStack],
+        equipped: Option<Entity>,
+        position: Vec3,
+        stations: &[(Vec3, StationType)],
+    ) -> Result<Vec<(Entity, u32)>, ErrorMessage> {
+        if let Some(tool) = self.tool {
+            if !stacks.iter().any(|stack| stack.item_type == tool) {
+                return Err(ErrorMessage::MissingTool);
+            }
+        }
+
+        if let Some(station) = self.station {
+            let in_range = stations.iter().any(|(station_position, station_type)| {
+                *station_type == station
+                    && station_position.distance(position) <= CRAFTING_STATION_RANGE
+            });
+
+            if !in_range {
+                return Err(ErrorMessage::NoCraftingStation);
+            }
+        }
+
+        let mut consumed = Vec::new();
+        for (input_type, count) in self.inputs.iter() {
+            let mut missing = *count;
+
+            for stack in stacks
+                .iter()
+                .filter(|stack| stack.item_type == *input_type && Some(stack.entity) != equipped)
+            {
+                if missing == 0 {
+                    break;
+                }
+
+                let taken = missing.min(stack.quantity);
+                consumed.push((stack.entity, taken));
+                missing -= taken;
+            }
+
+            if missing > 0 {
+                return Err(ErrorMessage::MissingIngredients);
+            }
+        }
+
+        Ok(consumed)
+    }
+
+    pub fn description(&self) -> String {
+        let inputs: Vec<String> = self
+            .inputs
+            .iter()
+            .map(|(item_type, count)| format!("{}x {}", count, item_type.name()))
+            .collect();
+
+        let mut description = format!("{} ({})", self.name, inputs.join(", "));
+
+        if let Some(station) = self.station {
+            description.push_str(&format!(" @{}", station.name()));
+        }
+
+        if let Some(tool) = self.tool {
+            description.push_str(&format!(" +{}", tool.name()));
+        }
+
+        description
+    }
+}
+
+/// Every recipe known by the game, referenced by index
+pub struct RecipeBook(pub Vec<Recipe>);
+
+impl RecipeBook {
+    pub fn get(&self, index: usize) -> Result<&Recipe, ErrorMessage> {
+        self.0.get(index).ok_or(ErrorMessage::UnknownRecipe)
+    }
+}
+
+impl Default for RecipeBook {
+    fn default() -> Self {
+        Self(vec![
+            Recipe {
+                name: "Hammer",
+                inputs: vec![(ItemType::Wood, 1), (ItemType::Stone, 1)],
+                outputs: vec![(ItemType::Hammer, 1)],
+                station: None,
+                tool: None,
+                craft_time: 1.,
+            },
+            Recipe {
+                name: "Shovel",
+                inputs: vec![(ItemType::Wood, 2), (ItemType::Stone, 1)],
+                outputs: vec![(ItemType::Shovel, 1)],
+                station: None,
+                tool: None,
+                craft_time: 1.5,
+            },
+            Recipe {
+                name: "Axe",
+                inputs: vec![(ItemType::Wood, 2), (ItemType::Stone, 2)],
+                outputs: vec![(ItemType::Axe, 1)],
+                station: Some(StationType::Workbench),
+                tool: None,
+                craft_time: 2.,
+            },
+            Recipe {
+                name: "Pickaxe",
+                inputs: vec![(ItemType::Wood, 2), (ItemType::Stone, 3)],
+                outputs: vec![(ItemType::Pickaxe, 1)],
+                station: Some(StationType::Workbench),
+                tool: None,
+                craft_time: 2.,
+            },
+            Recipe {
+                name: "Iron ingot",
+                inputs: vec![(ItemType::IronOre, 2)],
+                outputs: vec![(ItemType::IronIngot, 1)],
+                station: Some(StationType::Furnace),
+                tool: None,
+                craft_time: 3.,
+            },
+            Recipe {
+                name: "Sword",
+                inputs: vec![(ItemType::Wood, 1), (ItemType::IronIngot, 2)],
+                outputs: vec![(ItemType::Sword, 1)],
+                station: Some(StationType::Workbench),
+                tool: Some(ItemType::Hammer),
+                craft_time: 3.,
+            },
+            Recipe {
+                name: "Spear",
+                inputs: vec![(ItemType::Wood, 3), (ItemType::IronIngot, 1)],
+                outputs: vec![(ItemType::Spear, 1)],
+                station: Some(StationType::Workbench),
+                tool: Some(ItemType::Hammer),
+                craft_time: 3.,
+            },
+            Recipe {
+                name: "Bow",
+                inputs: vec![(ItemType::Wood, 3), (ItemType::Fiber, 3)],
+                outputs: vec![(ItemType::Bow, 1)],
+                station: Some(StationType::Workbench),
+                tool: None,
+                craft_time: 2.,
+            },
+            Recipe {
+                name: "Arrows",
+                inputs: vec![(ItemType::Wood, 1), (ItemType::Stone, 1)],
+                outputs: vec![(ItemType::Arrow, 5)],
+                station: None,
+                tool: None,
+                craft_time: 1.,
+            },
+            Recipe {
+                name: "Healing potion",
+                inputs: vec![(ItemType::Fiber, 3)],
+                outputs: vec![(ItemType::HealingPotion, 1)],
+                station: None,
+                tool: None,
+                craft_time: 1.,
+            },
+            Recipe {
+                name: "Cooked meal",
+                inputs: vec![(ItemType::Fiber, 2), (ItemType::Wood, 1)],
+                outputs: vec![(ItemType::CookedMeal, 1)],
+                station: Some(StationType::Furnace),
+                tool: None,
+                craft_time: 2.,
+            },
+            Recipe {
+                name: "Bomb",
+                inputs: vec![
+                    (ItemType::IronOre, 1),
+                    (ItemType::Stone, 1),
+                    (ItemType::Fiber, 1),
+                ],
+                outputs: vec![(ItemType::Bomb, 1)],
+                station: Some(StationType::Workbench),
+                tool: None,
+                craft_time: 2.,
+            },
+            Recipe {
+                name: "Antidote",
+                inputs: vec![(ItemType::Fiber, 2), (ItemType::Stone, 1)],
+                outputs: vec![(ItemType::Antidote, 1)],
+                station: Some(StationType::Furnace),
+                tool: None,
+                craft_time: 1.5,
+            },
+            Recipe {
+                name: "Bandages",
+                inputs: vec![(ItemType::Fiber, 3)],
+                outputs: vec![(ItemType::Bandage, 2)],
+                station: None,
+                tool: None,
+                craft_time: 1.,
+            },
+        ])
+    }
+}
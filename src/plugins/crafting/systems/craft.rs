@@ -0,0 +1,150 @@
+use bevy::prelude::*;
+
+use crate::{
+    plugins::{
+        crafting::{
+            crafting_events::{CraftItemEvent, ItemCraftedEvent},
+            crafting_plugin::Crafting,
+            recipes::{CraftingStation, RecipeBook, StationType},
+        },
+        creature::creature_plugin::Creature,
+        items::{
+            items_plugin::{Belt, EquippedItem, Inventory, Item, Quantity, StoreItemsEvent},
+            systems::inventory::stack_of,
+        },
+    },
+    utils::error::ErrorMessage,
+};
+
+/// # start_crafting_system
+/// Check that the creature can craft the requested recipe
+///
+/// Insert a Crafting component which is completed by crafting_progress_system
+///
+pub fn start_crafting_system(
+    mut commands: Commands,
+    recipe_book: Res<RecipeBook>,
+    creature_q: Query<(&Transform, &Inventory, &EquippedItem, Option<&Crafting>), With<Creature>>,
+    item_q: Query<(&Item, Option<&Quantity>)>,
+    station_q: Query<(&Transform, &CraftingStation)>,
+    mut ev_craft_item: EventReader<CraftItemEvent>,
+) {
+    let stations: Vec<(Vec3, StationType)> = station_q
+        .iter()
+        .map(|(transform, station)| (transform.translation, station.0))
+        .collect();
+
+    for ev in ev_craft_item.iter() {
+        let mut closure = || {
+            let (transform, inventory, equipped, crafting) = creature_q.get(ev.0)?;
+
+            if crafting.is_some() {
+                return Err(ErrorMessage::AlreadyCrafting);
+            }
+
+            let recipe = recipe_book.get(ev.1)?;
+            let stacks = inventory.stacks(|entity| {
+                item_q
+                    .get(entity)
+                    .ok()
+                    .map(|(item, quantity)| stack_of(item, quantity))
+            });
+            recipe.collect_inputs(&stacks, equipped.0, transform.translation, &stations)?;
+
+            println!("Start crafting {}", recipe.name);
+            commands.entity(ev.0).insert(Crafting {
+                recipe: ev.1,
+                timer: Timer::from_seconds(recipe.craft_time, false),
+            });
+
+            Ok::<(), ErrorMessage>(())
+        };
+
+        if let Err(error) = closure() {
+            println!("Cannot craft recipe {}: {}", ev.1, error);
+        }
+    }
+}
+
+/// # crafting_progress_system
+/// Tick the crafting timers
+///
+/// When a timer finishes, consume the recipe inputs from the creature inventory
+/// and store the newly created items instead
+///
+pub fn crafting_progress_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    recipe_book: Res<RecipeBook>,
+    mut creature_q: Query<
+        (
+            Entity,
+            &Transform,
+            &mut Inventory,
+            &mut Belt,
+            &EquippedItem,
+            &mut Crafting,
+        ),
+        With<Creature>,
+    >,
+    mut item_q: Query<(&Item, Option<&mut Quantity>)>,
+    station_q: Query<(&Transform, &CraftingStation)>,
+    mut ev_store_items: EventWriter<StoreItemsEvent>,
+    mut ev_item_crafted: EventWriter<ItemCraftedEvent>,
+) {
+    let stations: Vec<(Vec3, StationType)> = station_q
+        .iter()
+        .map(|(transform, station)| (transform.translation, station.0))
+        .collect();
+
+    for (entity, transform, mut inventory, mut belt, equipped, mut crafting) in
+        creature_q.iter_mut()
+    {
+        if !crafting.timer.tick(time.delta()).finished() {
+            continue;
+        }
+
+        commands.entity(entity).remove::<Crafting>();
+
+        let mut closure = || {
+            let recipe = recipe_book.get(crafting.recipe)?;
+
+            // Inventory or position may have changed since the crafting started
+            let stacks = inventory.stacks(|entity| {
+                item_q
+                    .get(entity)
+                    .ok()
+                    .map(|(item, quantity)| stack_of(item, quantity))
+            });
+            // The equipped item is left out, its visual would outlive it
+            let consumed =
+                recipe.collect_inputs(&stacks, equipped.0, transform.translation, &stations)?;
+
+            for (item, taken) in consumed {
+                if let Ok((_, Some(mut quantity))) = item_q.get_mut(item) {
+                    if quantity.0 > taken {
+                        quantity.0 -= taken;
+                        continue;
+                    }
+                }
+
+                inventory.0.retain(|entity| *entity != item);
+                belt.0.retain(|entity| *entity != item);
+                commands.entity(item).despawn_recursive();
+            }
+
+            for (output_type, count) in recipe.outputs.iter() {
+                ev_store_items.send(StoreItemsEvent(entity, *output_type, *count));
+            }
+
+            println!("Crafted {}", recipe.name);
+            ev_item_crafted.send(ItemCraftedEvent(entity, crafting.recipe));
+
+            Ok::<(), ErrorMessage>(())
+        };
+
+        if let Err(error) = closure() {
+            println!("Crafting failed: {}", error);
+        }
+    }
+}
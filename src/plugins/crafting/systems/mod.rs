@@ -0,0 +1,3 @@
+pub mod craft;
+pub mod stations;
+pub mod ui;
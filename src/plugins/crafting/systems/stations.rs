@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::Collider;
+
+use crate::plugins::crafting::recipes::{CraftingStation, StationType};
+
+/**
+ * DEV system used to place some crafting stations in the world
+ */
+pub fn dev_init_crafting_stations_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let stations = [
+        (StationType::Workbench, Vec3::new(6., -1.4, -4.)),
+        (StationType::Furnace, Vec3::new(-6., -1.15, -4.)),
+    ];
+
+    for (station_type, position) in stations {
+        let size = station_type.size();
+
+        commands
+            .spawn_bundle(PbrBundle {
+                mesh: meshes.add(Mesh::from(shape::Box::new(size.x, size.y, size.z))),
+                material: materials.add(station_type.color().into()),
+                transform: Transform::from_translation(position),
+                ..default()
+            })
+            .insert(Collider::cuboid(size.x / 2., size.y / 2., size.z / 2.))
+            .insert(CraftingStation(station_type));
+    }
+}
@@ -0,0 +1,205 @@
+use bevy::prelude::*;
+
+use crate::plugins::{
+    crafting::{
+        crafting_events::{CraftItemEvent, ToggleCraftingMenuEvent},
+        crafting_plugin::Crafting,
+        recipes::{CraftingStation, RecipeBook, StationType},
+    },
+    creature::creature_plugin::Player,
+    items::{
+        items_plugin::{EquippedItem, Inventory, InventoryCapacity, Item, Quantity},
+        systems::inventory::{stack_of, stacks_weight},
+    },
+};
+
+#[derive(Component)]
+pub struct CraftingMenu;
+
+#[derive(Component)]
+pub struct RecipeButton(pub usize);
+
+#[derive(Component)]
+pub struct CraftingStatusText;
+
+const MENU_COLOR: Color = Color::rgba(0., 0., 0., 0.8);
+const CRAFTABLE_BUTTON: Color = Color::rgb(0.024, 0.12, 0.25);
+const UNCRAFTABLE_BUTTON: Color = Color::rgb(0.25, 0.25, 0.25);
+const PRESSED_BUTTON: Color = Color::rgb(0.35, 0.75, 0.35);
+
+pub fn init_crafting_menu_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    recipe_book: Res<RecipeBook>,
+) {
+    let text_style = TextStyle {
+        font: asset_server.load("fonts/FiraCode-Bold.ttf"),
+        font_size: 18.0,
+        color: Color::WHITE,
+    };
+
+    commands
+        .spawn_bundle(NodeBundle {
+            visibility: Visibility { is_visible: false },
+            style: Style {
+                size: Size::new(Val::Px(480.), Val::Auto),
+                position_type: PositionType::Absolute,
+                position: UiRect {
+                    top: Val::Px(10.),
+                    right: Val::Px(10.),
+                    ..default()
+                },
+                // Ui y axis goes up, reverse it to list recipes from top to bottom
+                flex_direction: FlexDirection::ColumnReverse,
+                padding: UiRect::all(Val::Px(8.)),
+                ..default()
+            },
+            color: MENU_COLOR.into(),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn_bundle(TextBundle::from_section(
+                "Crafting",
+                TextStyle {
+                    font_size: 26.0,
+                    color: Color::GOLD,
+                    ..text_style.clone()
+                },
+            ));
+
+            for (index, recipe) in recipe_book.0.iter().enumerate() {
+                parent
+                    .spawn_bundle(ButtonBundle {
+                        style: Style {
+                            size: Size::new(Val::Percent(100.), Val::Px(30.)),
+                            margin: UiRect::all(Val::Px(2.)),
+                            align_items: AlignItems::Center,
+                            padding: UiRect::all(Val::Px(4.)),
+                            ..default()
+                        },
+                        color: UNCRAFTABLE_BUTTON.into(),
+                        ..default()
+                    })
+                    .with_children(|button| {
+                        button.spawn_bundle(TextBundle::from_section(
+                            recipe.description(),
+                            text_style.clone(),
+                        ));
+                    })
+                    .insert(RecipeButton(index));
+            }
+
+            parent
+                .spawn_bundle(TextBundle::from_section("", text_style.clone()))
+                .insert(CraftingStatusText);
+        })
+        .insert(CraftingMenu);
+}
+
+pub fn toggle_crafting_menu_system(
+    mut ev_toggle_crafting_menu: EventReader<ToggleCraftingMenuEvent>,
+    mut menu_q: Query<&mut Visibility, With<CraftingMenu>>,
+) {
+    for _ in ev_toggle_crafting_menu.iter() {
+        let mut visibility = match menu_q.get_single_mut() {
+            Ok(menu) => menu,
+            Err(_) => {
+                println!("Cannot find crafting menu");
+                return;
+            }
+        };
+
+        visibility.is_visible = !visibility.is_visible;
+    }
+}
+
+/**
+ * Craft the recipe when its button is clicked
+ */
+pub fn recipe_button_system(
+    interaction_q: Query<(&Interaction, &RecipeButton), Changed<Interaction>>,
+    player_q: Query<Entity, With<Player>>,
+    mut ev_craft_item: EventWriter<CraftItemEvent>,
+) {
+    for (interaction, recipe_button) in interaction_q.iter() {
+        if *interaction != Interaction::Clicked {
+            continue;
+        }
+
+        let player = match player_q.get_single() {
+            Ok(player) => player,
+            Err(_) => return,
+        };
+
+        ev_craft_item.send(CraftItemEvent(player, recipe_button.0));
+    }
+}
+
+/**
+ * Highlight the recipes the player can currently craft and display the crafting progress
+ */
+pub fn update_crafting_menu_system(
+    recipe_book: Res<RecipeBook>,
+    player_q: Query<
+        (
+            &Transform,
+            &Inventory,
+            &EquippedItem,
+            &InventoryCapacity,
+            Option<&Crafting>,
+        ),
+        With<Player>,
+    >,
+    item_q: Query<(&Item, Option<&Quantity>)>,
+    station_q: Query<(&Transform, &CraftingStation)>,
+    mut button_q: Query<(&Interaction, &RecipeButton, &mut UiColor)>,
+    mut status_q: Query<&mut Text, With<CraftingStatusText>>,
+) {
+    let (transform, inventory, equipped, capacity, crafting) = match player_q.get_single() {
+        Ok(player) => player,
+        Err(_) => return,
+    };
+
+    let stacks = inventory.stacks(|entity| {
+        item_q
+            .get(entity)
+            .ok()
+            .map(|(item, quantity)| stack_of(item, quantity))
+    });
+    let stations: Vec<(Vec3, StationType)> = station_q
+        .iter()
+        .map(|(transform, station)| (transform.translation, station.0))
+        .collect();
+
+    for (interaction, recipe_button, mut color) in button_q.iter_mut() {
+        let craftable = match recipe_book.get(recipe_button.0) {
+            Ok(recipe) => recipe
+                .collect_inputs(&stacks, equipped.0, transform.translation, &stations)
+                .is_ok(),
+            Err(_) => false,
+        };
+
+        *color = match (interaction, craftable) {
+            (Interaction::Clicked, true) => PRESSED_BUTTON.into(),
+            (_, true) => CRAFTABLE_BUTTON.into(),
+            (_, false) => UNCRAFTABLE_BUTTON.into(),
+        };
+    }
+
+    if let Ok(mut text) = status_q.get_single_mut() {
+        text.sections[0].value = match crafting.map(|c| (recipe_book.get(c.recipe), c)) {
+            Some((Ok(recipe), crafting)) => format!(
+                "Crafting {}... {:.0}%",
+                recipe.name,
+                crafting.timer.percent() * 100.
+            ),
+            _ => format!(
+                "Inventory {}/{} slots - {:.1}/{:.0} kg",
+                inventory.0.len(),
+                capacity.slots,
+                stacks_weight(&stacks),
+                capacity.max_weight
+            ),
+        };
+    }
+}
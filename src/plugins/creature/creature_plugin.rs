@@ -1,19 +1,29 @@
 use bevy::prelude::*;
 use bevy_text_mesh::TextMeshFont;
+use serde::Deserialize;
 
 use crate::{
     plugins::{
         items::items_plugin::{EquippedItem, Inventory, InventoryBundle},
         location::Location,
+        world::clock::DayPeriod,
     },
-    SystemsLabel, HUMAN_ATK, HUMAN_MAX_RANGE, HUMAN_STEP_DISTANCE, MONSTER_ATK, MONSTER_MAX_RANGE,
-    MONSTER_STEP_DISTANCE,
+    SystemsLabel, CREATURE_DENSITY, HUMAN_ATK, HUMAN_MAX_RANGE, HUMAN_MAX_STAMINA,
+    HUMAN_STEP_DISTANCE, INACTIVE_AGGRO_FACTOR, MONSTER_AGGRO_DISTANCE, MONSTER_ATK,
+    MONSTER_MAX_RANGE, MONSTER_MAX_STAMINA, MONSTER_STEP_DISTANCE,
 };
 
 use super::systems::{
+    ground::{ground_contact_system, GroundContact},
+    hit_zones::SpawnHitZonesChild,
     physical::{CreaturePhysicBundle, InsertPhysicalBody},
-    sensors::SpawnSwordRangeColliderChild,
-    stats::{change_consciousness_system, BrainState, CreatureName, Stats},
+    sensors::{swing_hitbox_system, update_weapon_hitbox_system},
+    stamina::{stamina_system, Stamina},
+    stats::{
+        change_consciousness_system, staggered_system, stats_buff_system, BrainState, CreatureName,
+        Stats,
+    },
+    status_effects::{status_effects_system, StatusEffectType, StatusEffects},
     ui::{display_hps_system, SpawnHpsTextMeshChild},
     visual::SpawnBodyMeshChild,
 };
@@ -27,14 +37,25 @@ impl Plugin for CreaturePlugin {
                 .before(SystemsLabel::Items),
         )
         .add_system(display_hps_system)
-        .add_system(change_consciousness_system);
+        .add_system(change_consciousness_system)
+        .add_system(stats_buff_system)
+        .add_system(status_effects_system)
+        .add_system(stamina_system)
+        .add_system(ground_contact_system)
+        .add_system(staggered_system)
+        .add_system(update_weapon_hitbox_system)
+        .add_system_to_stage(CoreStage::PostUpdate, swing_hitbox_system);
     }
 }
 
 #[derive(Bundle, Default)]
 pub struct CreatureBundle {
     pub creature_type: CreatureType,
+    pub activity: Activity,
     pub stats: Stats,
+    pub status_effects: StatusEffects,
+    pub stamina: Stamina,
+    pub ground_contact: GroundContact,
     pub brain_state: BrainState,
     pub name: CreatureName,
     pub location: Location,
@@ -46,7 +67,10 @@ impl CreatureBundle {
     pub fn new(creature_type: CreatureType, stats: Stats, name: CreatureName) -> Self {
         Self {
             creature_type,
+            activity: creature_type.activity(),
             stats,
+            status_effects: StatusEffects::new(creature_type.immunities()),
+            stamina: Stamina::new(creature_type.max_stamina()),
             name,
             ..default()
         }
@@ -62,10 +86,14 @@ pub struct Monster;
 #[derive(Component)]
 pub struct Player;
 
+/// Creature placed by a map, peaceful ones have no Monster component so the AI ignores them
+#[derive(Component)]
+pub struct Npc;
+
 // ------------------
 //
 // CreatureType
-#[derive(Clone, Copy, Component, Default, Debug)]
+#[derive(Clone, Copy, Component, Default, Debug, Deserialize)]
 pub enum CreatureType {
     #[default]
     Human,
@@ -94,6 +122,11 @@ impl CreatureType {
         }
     }
 
+    pub fn mass(&self) -> f32 {
+        let size = self.size();
+        size.x * size.y * size.z * CREATURE_DENSITY
+    }
+
     pub fn range(&self) -> f32 {
         match self {
             CreatureType::Human => HUMAN_MAX_RANGE,
@@ -101,12 +134,73 @@ impl CreatureType {
         }
     }
 
+    pub fn max_stamina(&self) -> f32 {
+        match self {
+            CreatureType::Human => HUMAN_MAX_STAMINA,
+            CreatureType::Monster => MONSTER_MAX_STAMINA,
+        }
+    }
+
+    /// Status effects the creature can never receive
+    pub fn immunities(&self) -> Vec<StatusEffectType> {
+        match self {
+            CreatureType::Human => Vec::new(),
+            // Claws poison their victims, monsters never receive Poison
+            CreatureType::Monster => vec![StatusEffectType::Poison],
+        }
+    }
+
+    pub fn activity(&self) -> Activity {
+        match self {
+            CreatureType::Human => Activity::Diurnal,
+            CreatureType::Monster => Activity::Nocturnal,
+        }
+    }
+
     pub fn attack(&self) -> f32 {
         match self {
             CreatureType::Human => HUMAN_ATK,
             CreatureType::Monster => MONSTER_ATK,
         }
     }
+
+    /// Whether the creature attacks the players when placed as an NPC
+    pub fn hostile(&self) -> bool {
+        matches!(self, CreatureType::Monster)
+    }
+}
+
+// ------------------
+//
+// Activity
+/// Period of the day when the creature is awake, it sleeps the rest of the time
+#[derive(Clone, Copy, Component, Default, Debug, PartialEq, Eq, Deserialize)]
+pub enum Activity {
+    #[default]
+    Diurnal,
+    Nocturnal,
+    /// Never sleeps, e.g. the storm monsters
+    Always,
+}
+
+impl Activity {
+    pub fn is_active(&self, period: DayPeriod) -> bool {
+        matches!(
+            (self, period),
+            (Activity::Always, _)
+                | (Activity::Diurnal, DayPeriod::Day)
+                | (Activity::Nocturnal, DayPeriod::Night)
+        )
+    }
+
+    /// Distance from which the creature notices a player, shorter while it should be resting
+    pub fn aggro_distance(&self, period: DayPeriod) -> f32 {
+        if self.is_active(period) {
+            MONSTER_AGGRO_DISTANCE
+        } else {
+            MONSTER_AGGRO_DISTANCE * INACTIVE_AGGRO_FACTOR
+        }
+    }
 }
 
 #[derive(Default, Component)]
@@ -143,8 +237,8 @@ impl CreatureConstructor {
         commands: &mut Commands,
         meshes: &mut ResMut<Assets<Mesh>>,
         materials: &mut ResMut<Assets<StandardMaterial>>,
-        asset_server: &mut Res<AssetServer>,
-    ) {
+        asset_server: &AssetServer,
+    ) -> Entity {
         let font: Handle<TextMeshFont> = asset_server.load("fonts/FiraSans-Medium.ttf#mesh");
 
         let mut parent = commands.spawn_bundle(SpatialBundle {
@@ -173,49 +267,32 @@ impl CreatureConstructor {
         self.insert_physical_body(&mut parent);
 
         // Spawn children
-        self.spawn_sword_range_collider_child(&mut parent);
+        self.spawn_hit_zones_child(&mut parent);
         self.spawn_hp_text_mesh_child(&mut parent, font);
         self.spawn_body_mesh_child(&mut parent, meshes, materials);
         // self.spawn_inventory_bundle(&mut parent);
+
+        parent.id()
     }
 }
 
 fn spawn_creatures(
     mut commands: Commands,
-    mut asset_server: Res<AssetServer>,
+    asset_server: Res<AssetServer>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
 ) -> () {
     let creature_type = CreatureType::Human;
     CreatureConstructor::new(
         creature_type.clone(),
-        Stats { atk: 1., hp: 100. },
+        Stats {
+            atk: 1.,
+            hp: 100.,
+            max_hp: 100.,
+        },
         CreatureName("Moi".into()),
         IsPlayer(true),
         CreaturePhysicBundle::new(creature_type, 0),
     )
-    .init(
-        &mut commands,
-        &mut meshes,
-        &mut materials,
-        &mut asset_server,
-    );
-
-    return;
-    // Spawn monsters
-    for _ in 0..100 {
-        CreatureConstructor::new(
-            CreatureType::Monster,
-            Stats { atk: 1., hp: 100. },
-            CreatureName("Monstre".into()),
-            IsPlayer(false),
-            CreaturePhysicBundle::new(CreatureType::Monster, 0),
-        )
-        .init(
-            &mut commands,
-            &mut meshes,
-            &mut materials,
-            &mut asset_server,
-        );
-    }
+    .init(&mut commands, &mut meshes, &mut materials, &asset_server);
 }
@@ -0,0 +1,118 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+use crate::{
+    plugins::{
+        creature::creature_plugin::{Creature, CreatureType},
+        world::{generation::WorldGenerator, world_plugin::Dormant},
+    },
+    GROUND_PROBE_DISTANCE, MAX_CLIMB_ANGLE, PATH_PROBE_DISTANCE,
+};
+
+/// Ground under the creature, updated every frame by the ground_contact_system
+#[derive(Component)]
+pub struct GroundContact {
+    pub grounded: bool,
+    pub normal: Vec3,
+    /// Set by a jump, the creature is not grounded until it starts falling
+    pub jumping: bool,
+}
+
+impl Default for GroundContact {
+    fn default() -> Self {
+        Self {
+            grounded: false,
+            normal: Vec3::Y,
+            jumping: false,
+        }
+    }
+}
+
+impl GroundContact {
+    pub fn is_too_steep(&self) -> bool {
+        self.normal.angle_between(Vec3::Y) > MAX_CLIMB_ANGLE
+    }
+
+    /// Velocity of a creature walking with the given horizontal velocity
+    ///
+    /// Grounded creatures follow the slope, the uphill part of the movement is removed
+    /// on slopes steeper than MAX_CLIMB_ANGLE, airborne creatures keep their vertical velocity
+    pub fn walk_velocity(&self, horizontal: Vec3, linvel: Vec3) -> Vec3 {
+        if !self.grounded {
+            return Vec3::new(horizontal.x, linvel.y, horizontal.z);
+        }
+
+        if self.is_too_steep() {
+            let downhill = Vec3::new(self.normal.x, 0., self.normal.z).normalize_or_zero();
+            let uphill = (-horizontal.dot(downhill)).max(0.);
+            let horizontal = horizontal + downhill * uphill;
+
+            return Vec3::new(horizontal.x, linvel.y.min(0.), horizontal.z);
+        }
+
+        let along_slope = horizontal - self.normal * horizontal.dot(self.normal);
+        along_slope.normalize_or_zero() * horizontal.length()
+    }
+}
+
+/**
+ * Cast a ray under each awake creature to find the ground it stands on
+ */
+pub fn ground_contact_system(
+    rapier_context: Res<RapierContext>,
+    mut creature_q: Query<
+        (&Transform, &CreatureType, &Velocity, &mut GroundContact),
+        (With<Creature>, Without<Dormant>),
+    >,
+) {
+    for (transform, creature_type, velocity, mut ground) in creature_q.iter_mut() {
+        if ground.jumping {
+            if velocity.linvel.y > 0. {
+                ground.grounded = false;
+                continue;
+            }
+            ground.jumping = false;
+        }
+
+        let hit = rapier_context.cast_ray_and_get_normal(
+            transform.translation,
+            -Vec3::Y,
+            creature_type.size().y / 2. + GROUND_PROBE_DISTANCE,
+            true,
+            QueryFilter::only_fixed().exclude_sensors(),
+        );
+
+        match hit {
+            Some((_, intersection)) => {
+                ground.grounded = true;
+                ground.normal = intersection.normal;
+            }
+            None => {
+                ground.grounded = false;
+                ground.normal = Vec3::Y;
+            }
+        }
+    }
+}
+
+/// Horizontal direction closest to the wanted one with a climbable terrain ahead,
+/// None if the creature is surrounded by steep slopes
+pub fn climbable_direction(
+    generator: &WorldGenerator,
+    position: Vec3,
+    direction: Vec3,
+) -> Option<Vec3> {
+    let from = Vec2::new(position.x, position.z);
+    let height = generator.ground_height(from);
+
+    // Try the wanted direction first, then turn more and more on both sides
+    [0., 0.5, -0.5, 1., -1., 1.5, -1.5]
+        .iter()
+        .map(|angle| Quat::from_rotation_y(*angle) * direction)
+        .find(|candidate| {
+            let to = from + Vec2::new(candidate.x, candidate.z) * PATH_PROBE_DISTANCE;
+            let rise = generator.ground_height(to) - height;
+
+            rise.atan2(PATH_PROBE_DISTANCE) <= MAX_CLIMB_ANGLE
+        })
+}
@@ -0,0 +1,78 @@
+use bevy::{ecs::system::EntityCommands, prelude::*};
+use bevy_inspector_egui::Inspectable;
+use bevy_rapier3d::prelude::*;
+
+use crate::{
+    plugins::creature::creature_plugin::CreatureConstructor, HIT_ZONE_GROUP, PROJECTILE_GROUP,
+    SWORD_SENSOR_GROUP,
+};
+
+// ------------------
+//
+// HitZoneType
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Inspectable)]
+pub enum HitZoneType {
+    Head,
+    Body,
+    Legs,
+}
+
+impl HitZoneType {
+    pub const ALL: [HitZoneType; 3] = [HitZoneType::Head, HitZoneType::Body, HitZoneType::Legs];
+
+    /// Bottom and top of the zone, as fractions of the creature height
+    pub fn span(&self) -> (f32, f32) {
+        match self {
+            HitZoneType::Head => (0.8, 1.),
+            HitZoneType::Body => (0.3, 0.8),
+            HitZoneType::Legs => (0., 0.3),
+        }
+    }
+
+    pub fn damage_multiplier(&self) -> f32 {
+        match self {
+            HitZoneType::Head => 2.,
+            HitZoneType::Body => 1.,
+            HitZoneType::Legs => 0.75,
+        }
+    }
+
+    /// Head hits are critical and stun the creature
+    pub fn is_critical(&self) -> bool {
+        matches!(self, HitZoneType::Head)
+    }
+}
+
+#[derive(Component, Inspectable)]
+pub struct HitZone(pub HitZoneType);
+
+// ----------------
+//
+// Hit zones colliders
+pub trait SpawnHitZonesChild {
+    fn spawn_hit_zones_child(&self, parent: &mut EntityCommands) -> ();
+}
+
+impl SpawnHitZonesChild for CreatureConstructor {
+    fn spawn_hit_zones_child(&self, cmds: &mut EntityCommands) {
+        let size = self.creature_type.size();
+
+        cmds.add_children(|parent| {
+            for zone in HitZoneType::ALL {
+                let (bottom, top) = zone.span();
+                let height = (top - bottom) * size.y;
+                let center = (bottom + top) / 2. * size.y - size.y / 2.;
+
+                parent
+                    .spawn_bundle(TransformBundle::from(Transform::from_xyz(0., center, 0.)))
+                    .insert(Collider::cuboid(size.x / 2., height / 2., size.z / 2.))
+                    .insert(Sensor)
+                    .insert(HitZone(zone))
+                    .insert(CollisionGroups::new(
+                        HIT_ZONE_GROUP,
+                        SWORD_SENSOR_GROUP | PROJECTILE_GROUP,
+                    ));
+            }
+        })
+    }
+}
@@ -1,6 +1,10 @@
+pub mod ground;
+pub mod hit_zones;
 pub mod inventory;
 pub mod physical;
 pub mod sensors;
+pub mod stamina;
 pub mod stats;
+pub mod status_effects;
 pub mod ui;
 pub mod visual;
@@ -4,7 +4,8 @@ use bevy_rapier3d::prelude::*;
 use crate::{
     plugins::creature::creature_plugin::{CreatureConstructor, CreatureType},
     utils::vec::RandVec3,
-    MONSTER_GROUP, PLAYER_GROUP, PROJECTILE_GROUP, SWORD_SENSOR_GROUP,
+    CREATURE_DENSITY, GROUND_LEVEL, MONSTER_GROUP, PICKABLE_GROUP, PLAYER_GROUP, PROJECTILE_GROUP,
+    RESOURCE_NODE_GROUP, SWORD_SENSOR_GROUP,
 };
 
 #[derive(Default, Bundle)]
@@ -29,9 +30,14 @@ pub struct CreaturePhysicBundle {
 
 impl CreaturePhysicBundle {
     pub fn new(creature_type: CreatureType, dominance_group: i8) -> Self {
+        let size = creature_type.size();
+        // Spawn standing on the ground
+        let mut translation = RandVec3::new();
+        translation.y = GROUND_LEVEL + size.y / 2.;
+
         Self {
             transform_bundle: TransformBundle::from_transform(Transform::from_translation(
-                RandVec3::new(),
+                translation,
             )),
             rigid_body: RigidBody::Dynamic,
             locked_axes: LockedAxes::ROTATION_LOCKED,
@@ -39,12 +45,9 @@ impl CreaturePhysicBundle {
                 linvel: Vec3::splat(0.),
                 angvel: Vec3::splat(0.),
             },
-            collider: Collider::cuboid(
-                creature_type.size().x / 2.,
-                creature_type.size().y / 2.,
-                creature_type.size().z / 2.,
-            ),
-            mass: ColliderMassProperties::Density(2000.0),
+            // A capsule slides over the edges of the terrain where a box would get stuck
+            collider: Collider::capsule_y(size.y / 2. - size.x / 2., size.x / 2.),
+            mass: ColliderMassProperties::Density(CREATURE_DENSITY),
             damping: Damping {
                 linear_damping: 1.,
                 angular_damping: 0.,
@@ -69,12 +72,23 @@ impl InsertPhysicalBody for CreatureConstructor {
 
         // Specific groups depending on creature type
         if self.is_player.0 {
-            parent.insert(CollisionGroups::new(PLAYER_GROUP, MONSTER_GROUP));
+            parent.insert(CollisionGroups::new(
+                PLAYER_GROUP,
+                MONSTER_GROUP
+                    | SWORD_SENSOR_GROUP
+                    | PROJECTILE_GROUP
+                    | RESOURCE_NODE_GROUP
+                    | PICKABLE_GROUP,
+            ));
         } else {
             parent.insert(ActiveEvents::COLLISION_EVENTS); // Enable events to detect projectile events
             parent.insert(CollisionGroups::new(
                 MONSTER_GROUP,
-                PLAYER_GROUP | MONSTER_GROUP | SWORD_SENSOR_GROUP | PROJECTILE_GROUP,
+                PLAYER_GROUP
+                    | MONSTER_GROUP
+                    | SWORD_SENSOR_GROUP
+                    | PROJECTILE_GROUP
+                    | RESOURCE_NODE_GROUP,
             ));
         }
     }
@@ -1,37 +1,116 @@
-use bevy::{ecs::system::EntityCommands, prelude::*};
+use bevy::prelude::*;
 use bevy_inspector_egui::Inspectable;
 use bevy_rapier3d::prelude::*;
-use std::f32::consts::PI;
 
 use crate::{
-    plugins::creature::creature_plugin::CreatureConstructor, MONSTER_GROUP, SWORD_SENSOR_GROUP,
+    plugins::{
+        creature::creature_plugin::Player,
+        items::items_plugin::{EquippedItem, Hitbox, Item, ItemType, Swing},
+    },
+    HIT_ZONE_GROUP, PLAYER_GROUP, RESOURCE_NODE_GROUP, SWORD_SENSOR_GROUP,
 };
 
+/// Sensor child of a creature, shaped by the weapon or tool it holds
 #[derive(Component, Inspectable)]
-pub struct PlayerSwordRangeSensor;
+pub struct WeaponHitbox;
 
 // ----------------
 //
-// Sword range collider
-pub trait SpawnSwordRangeColliderChild {
-    fn spawn_sword_range_collider_child(&self, parent: &mut EntityCommands) -> ();
+// Weapon hitbox
+pub fn spawn_weapon_hitbox_child(parent: &mut ChildBuilder, item_type: ItemType, is_player: bool) {
+    let hitbox = match item_type.hitbox() {
+        Some(hitbox) => hitbox,
+        None => return,
+    };
+
+    // Player weapons hit the monsters hit zones and resource nodes, monster weapons hit the player
+    let filters = if is_player {
+        HIT_ZONE_GROUP | RESOURCE_NODE_GROUP
+    } else {
+        PLAYER_GROUP
+    };
+
+    parent
+        .spawn_bundle(TransformBundle::from(hitbox.transform))
+        .insert(hitbox.collider)
+        .insert(Sensor)
+        .insert(WeaponHitbox)
+        .insert(CollisionGroups::new(SWORD_SENSOR_GROUP, filters));
+}
+
+/**
+ * Replace the hitbox of the creatures whose equipped item changed
+ *
+ * Monsters without equipped item fight with their claws
+ */
+pub fn update_weapon_hitbox_system(
+    mut commands: Commands,
+    creature_q: Query<
+        (Entity, &EquippedItem, Option<&Children>, Option<&Player>),
+        Changed<EquippedItem>,
+    >,
+    item_q: Query<&Item>,
+    hitbox_q: Query<Entity, With<WeaponHitbox>>,
+) {
+    for (creature, equipped_item, children, player) in creature_q.iter() {
+        if let Some(children) = children {
+            for hitbox in hitbox_q.iter_many(children) {
+                commands.entity(hitbox).despawn_recursive();
+            }
+        }
+
+        let item_type = match equipped_item.0.map(|item| item_q.get(item)) {
+            Some(Ok(item)) => item.item_type,
+            Some(Err(_)) => continue,
+            None if player.is_none() => ItemType::Claws,
+            None => continue,
+        };
+
+        commands.entity(creature).with_children(|parent| {
+            spawn_weapon_hitbox_child(parent, item_type, player.is_some());
+        });
+    }
 }
 
-impl SpawnSwordRangeColliderChild for CreatureConstructor {
-    fn spawn_sword_range_collider_child(&self, cmds: &mut EntityCommands) {
-        cmds.add_children(|parent| {
-            if self.is_player.0 {
-                parent
-                    .spawn_bundle(TransformBundle::from(Transform {
-                        translation: Vec3::new(1.5, 0., 0.),
-                        rotation: Quat::from_rotation_z(PI / 2.),
-                        ..default()
-                    }))
-                    .insert(Collider::cone(2., 3.))
-                    .insert(Sensor)
-                    .insert(PlayerSwordRangeSensor)
-                    .insert(CollisionGroups::new(SWORD_SENSOR_GROUP, MONSTER_GROUP));
+/// # swing_hitbox_system
+/// Shape the hitbox of the creature after the attack of the swing starting,
+/// and give it back the default hitbox of the item when the swing ends
+///
+/// Runs in PostUpdate so the swings removed by the update systems are seen in the same frame,
+/// a swing ending and a new one starting in the same frame keeps the hitbox of the new one
+///
+pub fn swing_hitbox_system(
+    swing_q: Query<(&Item, &Swing, &Parent), Added<Swing>>,
+    removed_swings: RemovedComponents<Swing>,
+    item_q: Query<(&Item, &Parent), Without<Swing>>,
+    creature_q: Query<&Children>,
+    mut hitbox_q: Query<(&mut Collider, &mut Transform), With<WeaponHitbox>>,
+) {
+    let mut shape_hitboxes = |creature: Entity, hitbox: Hitbox| {
+        let children = match creature_q.get(creature) {
+            Ok(children) => children,
+            Err(_) => return,
+        };
+
+        let mut hitboxes = hitbox_q.iter_many_mut(children);
+        while let Some((mut collider, mut transform)) = hitboxes.fetch_next() {
+            *collider = hitbox.collider.clone();
+            *transform = hitbox.transform;
+        }
+    };
+
+    for entity in removed_swings.iter() {
+        // The item may have been dropped or despawned with the swing
+        if let Ok((item, parent)) = item_q.get(entity) {
+            if let Some(hitbox) = item.item_type.hitbox() {
+                shape_hitboxes(parent.get(), hitbox);
             }
-        })
+        }
+    }
+
+    for (item, swing, parent) in swing_q.iter() {
+        if let Some(hitbox) = item.item_type.attack(swing.attack).hitbox {
+            shape_hitboxes(parent.get(), hitbox);
+        }
     }
 }
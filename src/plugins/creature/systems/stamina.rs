@@ -0,0 +1,78 @@
+use bevy::prelude::*;
+
+use crate::{
+    plugins::creature::creature_plugin::Creature, STAMINA_EXHAUSTION_THRESHOLD, STAMINA_REGEN,
+    STAMINA_REGEN_DELAY,
+};
+
+/// Resource spent by attacks, shots, blocks, sprints and dodges, regenerating after a delay without spending
+///
+/// A creature emptying its stamina is exhausted: it moves slower and cannot spend stamina
+/// until it regenerates above STAMINA_EXHAUSTION_THRESHOLD of its maximum
+#[derive(Component)]
+pub struct Stamina {
+    pub current: f32,
+    pub max: f32,
+    pub exhausted: bool,
+    pub regen_delay: Timer,
+}
+
+impl Default for Stamina {
+    fn default() -> Self {
+        Self::new(100.)
+    }
+}
+
+impl Stamina {
+    pub fn new(max: f32) -> Self {
+        Self {
+            current: max,
+            max,
+            exhausted: false,
+            regen_delay: Timer::from_seconds(STAMINA_REGEN_DELAY, false),
+        }
+    }
+
+    /// Spend stamina for an action, returns false when exhausted
+    ///
+    /// The action is allowed as long as some stamina is left, even if it costs more
+    pub fn try_consume(&mut self, cost: f32) -> bool {
+        if self.exhausted {
+            return false;
+        }
+
+        if cost <= 0. {
+            return true;
+        }
+
+        self.current = (self.current - cost).max(0.);
+        self.regen_delay.reset();
+
+        if self.current <= 0. {
+            self.exhausted = true;
+        }
+
+        true
+    }
+
+    pub fn percent(&self) -> f32 {
+        self.current / self.max
+    }
+}
+
+/**
+ * Regenerate the stamina once the delay since the last spending is over, and end the exhaustion
+ */
+pub fn stamina_system(time: Res<Time>, mut creatures_q: Query<&mut Stamina, With<Creature>>) {
+    for mut stamina in creatures_q.iter_mut() {
+        if !stamina.regen_delay.tick(time.delta()).finished() || stamina.current >= stamina.max {
+            continue;
+        }
+
+        stamina.current = (stamina.current + STAMINA_REGEN * time.delta_seconds()).min(stamina.max);
+
+        if stamina.exhausted && stamina.percent() >= STAMINA_EXHAUSTION_THRESHOLD {
+            stamina.exhausted = false;
+        }
+    }
+}
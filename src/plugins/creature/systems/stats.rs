@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use std::time::Duration;
 
 use crate::{plugins::creature::creature_plugin::Creature, MONSTER_STUN_COOLDOWN};
 
@@ -11,9 +12,27 @@ pub struct LastAttack(pub Timer);
 #[derive(Copy, Clone, Component, Default)]
 pub struct Stats {
     pub hp: f32,
+    pub max_hp: f32,
     pub atk: f32,
 }
 
+impl Stats {
+    pub fn heal(&mut self, amount: f32) {
+        self.hp = (self.hp + amount).min(self.max_hp);
+    }
+}
+
+/// Temporary bonus added to the creature Stats, removed when the timer finishes
+#[derive(Component)]
+pub struct StatsBuff {
+    pub atk: f32,
+    pub timer: Timer,
+}
+
+/// Knocked back creature, unable to move or attack until the timer finishes
+#[derive(Component)]
+pub struct Staggered(pub Timer);
+
 #[derive(Component, Default)]
 pub struct BrainState {
     pub conscious: ConsciousnessStateEnum,
@@ -27,6 +46,13 @@ impl BrainState {
             stun_at: Timer::from_seconds(MONSTER_STUN_COOLDOWN, false),
         }
     }
+
+    /// Stun the creature for the given duration
+    pub fn stun(&mut self, duration: f32) {
+        self.conscious = ConsciousnessStateEnum::Stun;
+        self.stun_at.set_duration(Duration::from_secs_f32(duration));
+        self.stun_at.reset();
+    }
 }
 
 #[derive(Clone, Default, Debug, Component, PartialEq)]
@@ -48,8 +74,35 @@ pub fn change_consciousness_system(
     mut creatures_q: Query<&mut BrainState, With<Creature>>,
 ) {
     for mut creature_brain_state in creatures_q.iter_mut() {
-        if creature_brain_state.stun_at.tick(time.delta()).finished() {
+        if creature_brain_state.stun_at.tick(time.delta()).finished()
+            && creature_brain_state.conscious == ConsciousnessStateEnum::Stun
+        {
             creature_brain_state.conscious = ConsciousnessStateEnum::Awake;
         }
     }
 }
+
+pub fn stats_buff_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut creatures_q: Query<(Entity, &mut Stats, &mut StatsBuff), With<Creature>>,
+) {
+    for (entity, mut stats, mut buff) in creatures_q.iter_mut() {
+        if buff.timer.tick(time.delta()).finished() {
+            stats.atk -= buff.atk;
+            commands.entity(entity).remove::<StatsBuff>();
+        }
+    }
+}
+
+pub fn staggered_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut creatures_q: Query<(Entity, &mut Staggered), With<Creature>>,
+) {
+    for (entity, mut staggered) in creatures_q.iter_mut() {
+        if staggered.0.tick(time.delta()).finished() {
+            commands.entity(entity).remove::<Staggered>();
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use bevy::prelude::*;
+
+use crate::plugins::{
+    combat::combat_events::KillMonsterEvent,
+    creature::{
+        creature_plugin::{Creature, Monster, Player},
+        systems::stats::Stats,
+    },
+    player::player_events::KillPlayerEvent,
+};
+
+/// How a new application of an effect combines with the one already running
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StackingRule {
+    /// Restart the duration, keeping a single stack
+    Refresh,
+    /// Add a stack up to the maximum and restart the duration
+    Stack(u32),
+}
+
+// ------------------
+//
+// StatusEffectType
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StatusEffectType {
+    Poison,
+    Burn,
+    Slow,
+    Bleed,
+    Regeneration,
+}
+
+impl StatusEffectType {
+    pub fn duration(&self) -> f32 {
+        match self {
+            StatusEffectType::Poison => 8.,
+            StatusEffectType::Burn => 4.,
+            StatusEffectType::Slow => 3.,
+            StatusEffectType::Bleed => 6.,
+            StatusEffectType::Regeneration => 10.,
+        }
+    }
+
+    /// Damages dealt every second for each stack, negative values heal
+    pub fn tick_damage(&self) -> f32 {
+        match self {
+            StatusEffectType::Poison => 2.,
+            StatusEffectType::Burn => 5.,
+            StatusEffectType::Slow => 0.,
+            StatusEffectType::Bleed => 1.5,
+            StatusEffectType::Regeneration => -4.,
+        }
+    }
+
+    pub fn stacking(&self) -> StackingRule {
+        match self {
+            StatusEffectType::Poison => StackingRule::Stack(5),
+            StatusEffectType::Bleed => StackingRule::Stack(3),
+            _ => StackingRule::Refresh,
+        }
+    }
+
+    pub fn speed_multiplier(&self, _stacks: u32) -> f32 {
+        match self {
+            StatusEffectType::Slow => 0.5,
+            _ => 1.,
+        }
+    }
+
+    /// Multiplier of the damages dealt by the affected creature
+    pub fn atk_multiplier(&self, stacks: u32) -> f32 {
+        match self {
+            StatusEffectType::Poison => 1. - 0.05 * stacks as f32,
+            _ => 1.,
+        }
+    }
+
+    /// Multiplier of the damages received by the affected creature
+    pub fn damage_taken_multiplier(&self, stacks: u32) -> f32 {
+        match self {
+            StatusEffectType::Bleed => 1. + 0.1 * stacks as f32,
+            _ => 1.,
+        }
+    }
+}
+
+pub struct StatusEffect {
+    pub effect_type: StatusEffectType,
+    pub stacks: u32,
+    pub duration: Timer,
+    pub tick: Timer,
+}
+
+impl StatusEffect {
+    pub fn new(effect_type: StatusEffectType) -> Self {
+        Self {
+            effect_type,
+            stacks: 1,
+            duration: Timer::from_seconds(effect_type.duration(), false),
+            tick: Timer::from_seconds(1., true),
+        }
+    }
+}
+
+/// Timed effects running on a creature, and the effects it cannot receive
+#[derive(Component, Default)]
+pub struct StatusEffects {
+    pub effects: Vec<StatusEffect>,
+    /// Permanent immunities, e.g. from the creature type
+    pub immunities: Vec<StatusEffectType>,
+    /// Temporary immunities, e.g. from an antidote
+    pub timed_immunities: Vec<(StatusEffectType, Timer)>,
+}
+
+impl StatusEffects {
+    pub fn new(immunities: Vec<StatusEffectType>) -> Self {
+        Self {
+            immunities,
+            ..default()
+        }
+    }
+
+    pub fn has(&self, effect_type: StatusEffectType) -> bool {
+        self.effects
+            .iter()
+            .any(|effect| effect.effect_type == effect_type)
+    }
+
+    pub fn is_immune(&self, effect_type: StatusEffectType) -> bool {
+        self.immunities.contains(&effect_type)
+            || self
+                .timed_immunities
+                .iter()
+                .any(|(immunity, _)| *immunity == effect_type)
+    }
+
+    /// Apply an effect following its stacking rule, returns false if the creature is immune
+    pub fn apply(&mut self, effect_type: StatusEffectType) -> bool {
+        if self.is_immune(effect_type) {
+            return false;
+        }
+
+        match self
+            .effects
+            .iter_mut()
+            .find(|effect| effect.effect_type == effect_type)
+        {
+            Some(effect) => {
+                if let StackingRule::Stack(max_stacks) = effect_type.stacking() {
+                    effect.stacks = (effect.stacks + 1).min(max_stacks);
+                }
+                effect.duration.reset();
+            }
+            None => self.effects.push(StatusEffect::new(effect_type)),
+        }
+
+        true
+    }
+
+    /// Remove an effect and prevent it for the given duration
+    pub fn cure(&mut self, effect_type: StatusEffectType, immunity_time: f32) {
+        self.effects
+            .retain(|effect| effect.effect_type != effect_type);
+        self.timed_immunities
+            .retain(|(immunity, _)| *immunity != effect_type);
+        self.timed_immunities
+            .push((effect_type, Timer::from_seconds(immunity_time, false)));
+    }
+
+    pub fn speed_multiplier(&self) -> f32 {
+        self.effects
+            .iter()
+            .map(|effect| effect.effect_type.speed_multiplier(effect.stacks))
+            .product()
+    }
+
+    pub fn atk_multiplier(&self) -> f32 {
+        self.effects
+            .iter()
+            .map(|effect| effect.effect_type.atk_multiplier(effect.stacks))
+            .product()
+    }
+
+    pub fn damage_taken_multiplier(&self) -> f32 {
+        self.effects
+            .iter()
+            .map(|effect| effect.effect_type.damage_taken_multiplier(effect.stacks))
+            .product()
+    }
+}
+
+/**
+ * Apply the tick damages of the status effects, remove the expired effects and immunities
+ */
+pub fn status_effects_system(
+    time: Res<Time>,
+    mut creatures_q: Query<
+        (Entity, &mut StatusEffects, &mut Stats, Option<&Player>),
+        With<Creature>,
+    >,
+    monster_q: Query<(), With<Monster>>,
+    mut ev_kill_monster: EventWriter<KillMonsterEvent>,
+    mut ev_kill_player: EventWriter<KillPlayerEvent>,
+) {
+    for (entity, mut status_effects, mut stats, player) in creatures_q.iter_mut() {
+        if stats.hp <= 0. {
+            continue;
+        }
+
+        let mut tick_damage = 0.;
+        for effect in status_effects.effects.iter_mut() {
+            effect.duration.tick(time.delta());
+            let ticks = effect.tick.tick(time.delta()).times_finished_this_tick();
+            tick_damage += effect.effect_type.tick_damage() * effect.stacks as f32 * ticks as f32;
+        }
+
+        status_effects
+            .effects
+            .retain(|effect| !effect.duration.finished());
+
+        for (_, timer) in status_effects.timed_immunities.iter_mut() {
+            timer.tick(time.delta());
+        }
+        status_effects
+            .timed_immunities
+            .retain(|(_, timer)| !timer.finished());
+
+        if tick_damage < 0. {
+            stats.heal(-tick_damage);
+        } else if tick_damage > 0. {
+            stats.hp -= tick_damage;
+        }
+
+        if stats.hp > 0. {
+            continue;
+        }
+
+        if player.is_some() {
+            stats.hp = 0.;
+            ev_kill_player.send(KillPlayerEvent());
+        } else if monster_q.contains(entity) {
+            // Despawned by the kill_monster_system
+            ev_kill_monster.send(KillMonsterEvent(entity));
+        }
+    }
+}
@@ -1,6 +1,13 @@
 use bevy::{ecs::system::EntityCommands, prelude::*};
+use bevy_mod_raycast::RayCastMesh;
 
-use crate::plugins::creature::creature_plugin::CreatureConstructor;
+use crate::plugins::{
+    creature::creature_plugin::CreatureConstructor, player::control::mouse::MouseRaycastSet,
+};
+
+/// Visible body of a creature, monster bodies can be clicked to target them
+#[derive(Component)]
+pub struct BodyMesh;
 
 // ----------------
 //
@@ -22,7 +29,7 @@ impl SpawnBodyMeshChild for CreatureConstructor {
         materials: &mut ResMut<Assets<StandardMaterial>>,
     ) {
         cmds.add_children(|parent| {
-            parent.spawn_bundle(PbrBundle {
+            let mut body = parent.spawn_bundle(PbrBundle {
                 mesh: meshes.add(Mesh::from(shape::Box::new(
                     self.creature_type.size().x,
                     self.creature_type.size().y,
@@ -32,6 +39,12 @@ impl SpawnBodyMeshChild for CreatureConstructor {
                 transform: Transform::from_xyz(0., 0., 0.),
                 ..default()
             });
+
+            body.insert(BodyMesh);
+
+            if !self.is_player.0 {
+                body.insert(RayCastMesh::<MouseRaycastSet>::default());
+            }
         });
     }
 }
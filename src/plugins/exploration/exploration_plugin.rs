@@ -0,0 +1,91 @@
+use std::{collections::HashSet, fs, path::Path};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    plugins::world::generation::WorldGenerator, utils::error::ErrorMessage, EXPLORATION_CELL_SIZE,
+    EXPLORATION_SAVE_FOLDER, EXPLORATION_SAVE_INTERVAL,
+};
+
+use super::systems::{
+    explore::{explore_system, load_exploration_system, save_exploration_system},
+    minimap::{spawn_minimap_system, update_minimap_system},
+};
+
+pub struct ExplorationPlugin;
+impl Plugin for ExplorationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ExplorationSaveTimer>()
+            .add_startup_system(spawn_minimap_system)
+            .add_system(load_exploration_system)
+            .add_system(explore_system)
+            .add_system(save_exploration_system)
+            .add_system(update_minimap_system);
+    }
+}
+
+/// Time between two saves of the explorations
+pub struct ExplorationSaveTimer(pub Timer);
+
+impl Default for ExplorationSaveTimer {
+    fn default() -> Self {
+        Self(Timer::from_seconds(EXPLORATION_SAVE_INTERVAL, true))
+    }
+}
+
+/// # Exploration
+/// Cells of the world grid explored by a player, saved in `saves/exploration/<player name>.ron`
+///
+/// The world is split in square cells of EXPLORATION_CELL_SIZE,
+/// cell (0, 0) starts at the origin and goes toward +x and +z
+///
+#[derive(Component, Default, Serialize, Deserialize)]
+pub struct Exploration {
+    pub cells: HashSet<(i32, i32)>,
+    /// Cells explored since the last save
+    #[serde(skip)]
+    pub unsaved: bool,
+}
+
+impl Exploration {
+    /// Cell containing a world position
+    pub fn cell(position: Vec3) -> (i32, i32) {
+        (
+            (position.x / EXPLORATION_CELL_SIZE).floor() as i32,
+            (position.z / EXPLORATION_CELL_SIZE).floor() as i32,
+        )
+    }
+
+    /// Number of cells per side of the world
+    pub fn cells_per_side(generator: &WorldGenerator) -> i32 {
+        (2. * generator.half_extent() / EXPLORATION_CELL_SIZE).ceil() as i32
+    }
+
+    /// Part of the world explored, from 0 to 100
+    pub fn percentage(&self, generator: &WorldGenerator) -> f32 {
+        let cells_per_side = Exploration::cells_per_side(generator);
+        100. * self.cells.len() as f32 / (cells_per_side * cells_per_side) as f32
+    }
+
+    /// Exploration saved for the player, empty if the player never played
+    pub fn load(player_name: &str) -> Result<Self, ErrorMessage> {
+        let path = Exploration::path(player_name);
+        if !Path::new(&path).exists() {
+            return Ok(Exploration::default());
+        }
+
+        let content = fs::read_to_string(path)?;
+        Ok(ron::from_str(&content)?)
+    }
+
+    pub fn save(&self, player_name: &str) -> Result<(), ErrorMessage> {
+        fs::create_dir_all(EXPLORATION_SAVE_FOLDER)?;
+        fs::write(Exploration::path(player_name), ron::to_string(self)?)?;
+        Ok(())
+    }
+
+    fn path(player_name: &str) -> String {
+        format!("{}/{}.ron", EXPLORATION_SAVE_FOLDER, player_name)
+    }
+}
@@ -0,0 +1,2 @@
+pub mod exploration_plugin;
+pub mod systems;
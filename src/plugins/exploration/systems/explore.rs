@@ -0,0 +1,91 @@
+use bevy::prelude::*;
+
+use crate::{
+    plugins::{
+        creature::{creature_plugin::Player, systems::stats::CreatureName},
+        exploration::exploration_plugin::{Exploration, ExplorationSaveTimer},
+        world::generation::WorldGenerator,
+    },
+    EXPLORATION_CELL_SIZE, EXPLORATION_RADIUS,
+};
+
+/**
+ * Give the new players the exploration saved under their name
+ */
+pub fn load_exploration_system(
+    mut commands: Commands,
+    player_q: Query<(Entity, &CreatureName), Added<Player>>,
+) {
+    for (entity, name) in player_q.iter() {
+        let exploration = match Exploration::load(&name.0) {
+            Ok(exploration) => exploration,
+            Err(error) => {
+                println!("Error while loading the exploration: {}", error);
+                Exploration::default()
+            }
+        };
+
+        commands.entity(entity).insert(exploration);
+    }
+}
+
+/**
+ * Reveal the cells around the players, inside the world borders
+ */
+pub fn explore_system(
+    generator: Res<WorldGenerator>,
+    mut player_q: Query<(&Transform, &mut Exploration), With<Player>>,
+) {
+    let half_extent = generator.half_extent();
+    let reach = (EXPLORATION_RADIUS / EXPLORATION_CELL_SIZE).ceil() as i32;
+
+    for (transform, mut exploration) in player_q.iter_mut() {
+        let position = transform.translation;
+        let (x, z) = Exploration::cell(position);
+
+        let new_cells: Vec<(i32, i32)> = (x - reach..=x + reach)
+            .flat_map(|cell_x| (z - reach..=z + reach).map(move |cell_z| (cell_x, cell_z)))
+            .filter(|(cell_x, cell_z)| {
+                let center =
+                    Vec2::new(*cell_x as f32 + 0.5, *cell_z as f32 + 0.5) * EXPLORATION_CELL_SIZE;
+
+                center.x.abs() < half_extent
+                    && center.y.abs() < half_extent
+                    && center.distance(Vec2::new(position.x, position.z)) <= EXPLORATION_RADIUS
+            })
+            .filter(|cell| !exploration.cells.contains(cell))
+            .collect();
+
+        // Only touch the exploration when it changes, the minimap is redrawn on change
+        if new_cells.is_empty() {
+            continue;
+        }
+
+        exploration.cells.extend(new_cells);
+        exploration.unsaved = true;
+    }
+}
+
+/**
+ * Save the explorations with new cells at regular intervals
+ */
+pub fn save_exploration_system(
+    time: Res<Time>,
+    mut save_timer: ResMut<ExplorationSaveTimer>,
+    mut player_q: Query<(&CreatureName, &mut Exploration), With<Player>>,
+) {
+    if !save_timer.0.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    for (name, mut exploration) in player_q.iter_mut() {
+        if !exploration.unsaved {
+            continue;
+        }
+
+        match exploration.save(&name.0) {
+            Ok(()) => exploration.unsaved = false,
+            Err(error) => println!("Error while saving the exploration: {}", error),
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use bevy::{
+    prelude::*,
+    render::{
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+        texture::ImageSampler,
+    },
+};
+
+use crate::{
+    plugins::{
+        creature::creature_plugin::Player,
+        exploration::exploration_plugin::Exploration,
+        world::generation::{ChunkCoord, WorldGenerator},
+    },
+    EXPLORATION_CELL_SIZE, MINIMAP_SIZE,
+};
+
+/// Color of the cells never explored
+const UNEXPLORED_COLOR: [u8; 4] = [10, 10, 10, 255];
+const MARKER_SIZE: f32 = 6.;
+
+/// Image of the world grid, a pixel per cell
+#[derive(Component)]
+pub struct Minimap(pub Handle<Image>);
+
+/// Position of the player on the minimap
+#[derive(Component)]
+pub struct MinimapMarker;
+
+#[derive(Component)]
+pub struct ExplorationText;
+
+/**
+ * Spawn the minimap in the top right corner, with the explored percentage below it
+ */
+pub fn spawn_minimap_system(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    asset_server: Res<AssetServer>,
+    generator: Res<WorldGenerator>,
+) {
+    let cells_per_side = Exploration::cells_per_side(&generator) as u32;
+    let mut image = Image::new_fill(
+        Extent3d {
+            width: cells_per_side,
+            height: cells_per_side,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &UNEXPLORED_COLOR,
+        TextureFormat::Rgba8UnormSrgb,
+    );
+    // Keep the cells sharp
+    image.sampler_descriptor = ImageSampler::nearest();
+    let handle = images.add(image);
+
+    commands
+        .spawn_bundle(ImageBundle {
+            style: Style {
+                size: Size::new(Val::Px(MINIMAP_SIZE), Val::Px(MINIMAP_SIZE)),
+                position_type: PositionType::Absolute,
+                position: UiRect {
+                    top: Val::Px(10.),
+                    right: Val::Px(10.),
+                    ..default()
+                },
+                ..default()
+            },
+            image: UiImage(handle.clone()),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent
+                .spawn_bundle(NodeBundle {
+                    style: Style {
+                        size: Size::new(Val::Px(MARKER_SIZE), Val::Px(MARKER_SIZE)),
+                        position_type: PositionType::Absolute,
+                        ..default()
+                    },
+                    color: Color::RED.into(),
+                    ..default()
+                })
+                .insert(MinimapMarker);
+        })
+        .insert(Minimap(handle));
+
+    commands
+        .spawn_bundle(
+            TextBundle::from_section(
+                "Explored: 0.0%",
+                TextStyle {
+                    font: asset_server.load("fonts/FiraCode-Bold.ttf"),
+                    font_size: 18.,
+                    color: Color::WHITE,
+                },
+            )
+            .with_style(Style {
+                position_type: PositionType::Absolute,
+                position: UiRect {
+                    top: Val::Px(MINIMAP_SIZE + 15.),
+                    right: Val::Px(10.),
+                    ..default()
+                },
+                ..default()
+            }),
+        )
+        .insert(ExplorationText);
+}
+
+/**
+ * Paint the cells explored by the player with the color of their biome, and move the marker
+ */
+pub fn update_minimap_system(
+    generator: Res<WorldGenerator>,
+    mut images: ResMut<Assets<Image>>,
+    minimap_q: Query<&Minimap>,
+    mut marker_q: Query<&mut Style, With<MinimapMarker>>,
+    mut text_q: Query<&mut Text, With<ExplorationText>>,
+    player_q: Query<(&Transform, &Exploration, ChangeTrackers<Exploration>), With<Player>>,
+) {
+    let (transform, exploration, exploration_tracker) = match player_q.iter().next() {
+        Some(player) => player,
+        None => return,
+    };
+
+    let cells_per_side = Exploration::cells_per_side(&generator);
+    // The world is centered on the origin, cell (0, 0) is in the middle of the image
+    let first_cell = -cells_per_side / 2;
+
+    // Row 0 is the north of the world, at the top of the screen
+    let (x, z) = Exploration::cell(transform.translation);
+    let pixel_size = MINIMAP_SIZE / cells_per_side as f32;
+    for mut style in marker_q.iter_mut() {
+        style.position = UiRect {
+            left: Val::Px((x - first_cell) as f32 * pixel_size + (pixel_size - MARKER_SIZE) / 2.),
+            top: Val::Px((z - first_cell) as f32 * pixel_size + (pixel_size - MARKER_SIZE) / 2.),
+            ..default()
+        };
+    }
+
+    if !exploration_tracker.is_changed() {
+        return;
+    }
+
+    for mut text in text_q.iter_mut() {
+        text.sections[0].value = format!("Explored: {:.1}%", exploration.percentage(&generator));
+    }
+
+    for minimap in minimap_q.iter() {
+        let image = match images.get_mut(&minimap.0) {
+            Some(image) => image,
+            None => continue,
+        };
+
+        for (cell_x, cell_z) in exploration.cells.iter() {
+            let (column, row) = (cell_x - first_cell, cell_z - first_cell);
+            if !(0..cells_per_side).contains(&column) || !(0..cells_per_side).contains(&row) {
+                continue;
+            }
+
+            let center =
+                Vec3::new(*cell_x as f32 + 0.5, 0., *cell_z as f32 + 0.5) * EXPLORATION_CELL_SIZE;
+            let biome = generator.biome(ChunkCoord::from_position(center));
+            let color = biome
+                .ground_color()
+                .as_rgba_f32()
+                .map(|channel| (channel * 255.) as u8);
+
+            let index = 4 * (row * cells_per_side + column) as usize;
+            image.data[index..index + 4].copy_from_slice(&color);
+        }
+    }
+}
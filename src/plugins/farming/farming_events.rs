@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+
+/// Hit a resource node with the equipped item
+/// ### Param1 - {Entity} - The creature harvesting the node
+/// ### Param2 - {Entity} - The resource node entity
+pub struct HarvestResourceEvent(pub Entity, pub Entity);
+
+/// A resource node has been depleted and its yield stored in the creature inventory
+/// ### Param1 - {Entity} - The creature who harvested the node
+/// ### Param2 - {Entity} - The resource node entity
+pub struct ResourceHarvestedEvent(pub Entity, pub Entity);
@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+
+use super::{
+    farming_events::{HarvestResourceEvent, ResourceHarvestedEvent},
+    systems::harvest::{harvest_resource_system, respawn_resource_nodes_system},
+};
+
+pub struct FarmingPlugin;
+impl Plugin for FarmingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<HarvestResourceEvent>()
+            .add_event::<ResourceHarvestedEvent>()
+            .add_system(harvest_resource_system)
+            .add_system(respawn_resource_nodes_system);
+    }
+}
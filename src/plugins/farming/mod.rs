@@ -0,0 +1,4 @@
+pub mod farming_events;
+pub mod farming_plugin;
+pub mod resource_nodes;
+pub mod systems;
@@ -0,0 +1,108 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::{Collider, CollisionGroups};
+use serde::Deserialize;
+
+use crate::{
+    plugins::items::items_plugin::ItemType, MONSTER_GROUP, PLAYER_GROUP, PROJECTILE_GROUP,
+    RESOURCE_NODE_GROUP, SWORD_SENSOR_GROUP,
+};
+
+// ------------------
+//
+// ResourceNodeType
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+pub enum ResourceNodeType {
+    Tree,
+    Rock,
+    IronOre,
+    Plant,
+}
+
+impl ResourceNodeType {
+    pub fn max_hp(&self) -> f32 {
+        match self {
+            ResourceNodeType::Tree => 100.,
+            ResourceNodeType::Rock => 150.,
+            ResourceNodeType::IronOre => 200.,
+            ResourceNodeType::Plant => 20.,
+        }
+    }
+
+    /// Tool which has to be equipped to harvest the node
+    pub fn required_tool(&self) -> ItemType {
+        match self {
+            ResourceNodeType::Tree => ItemType::Axe,
+            ResourceNodeType::Rock => ItemType::Pickaxe,
+            ResourceNodeType::IronOre => ItemType::Pickaxe,
+            ResourceNodeType::Plant => ItemType::Shovel,
+        }
+    }
+
+    /// Items stored in the inventory when the node is depleted
+    pub fn yields(&self) -> (ItemType, u32) {
+        match self {
+            ResourceNodeType::Tree => (ItemType::Wood, 3),
+            ResourceNodeType::Rock => (ItemType::Stone, 3),
+            ResourceNodeType::IronOre => (ItemType::IronOre, 2),
+            ResourceNodeType::Plant => (ItemType::Fiber, 2),
+        }
+    }
+
+    pub fn respawn_time(&self) -> f32 {
+        match self {
+            ResourceNodeType::Tree => 30.,
+            ResourceNodeType::Rock => 45.,
+            ResourceNodeType::IronOre => 90.,
+            ResourceNodeType::Plant => 15.,
+        }
+    }
+
+    pub fn size(&self) -> Vec3 {
+        match self {
+            ResourceNodeType::Tree => Vec3::new(1., 4., 1.),
+            ResourceNodeType::Rock => Vec3::new(2., 1.2, 2.),
+            ResourceNodeType::IronOre => Vec3::new(1.5, 1., 1.5),
+            ResourceNodeType::Plant => Vec3::new(0.6, 0.6, 0.6),
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            ResourceNodeType::Tree => Color::DARK_GREEN,
+            ResourceNodeType::Rock => Color::GRAY,
+            ResourceNodeType::IronOre => Color::rgb(0.45, 0.3, 0.3),
+            ResourceNodeType::Plant => Color::LIME_GREEN,
+        }
+    }
+
+    pub fn collider(&self) -> Collider {
+        let size = self.size();
+        Collider::cuboid(size.x / 2., size.y / 2., size.z / 2.)
+    }
+
+    pub fn collision_groups(&self) -> CollisionGroups {
+        CollisionGroups::new(
+            RESOURCE_NODE_GROUP,
+            PLAYER_GROUP | MONSTER_GROUP | SWORD_SENSOR_GROUP | PROJECTILE_GROUP,
+        )
+    }
+}
+
+#[derive(Component)]
+pub struct ResourceNode {
+    pub node_type: ResourceNodeType,
+    pub hp: f32,
+}
+
+impl ResourceNode {
+    pub fn new(node_type: ResourceNodeType) -> Self {
+        Self {
+            node_type,
+            hp: node_type.max_hp(),
+        }
+    }
+}
+
+/// A harvested node waiting to respawn
+#[derive(Component)]
+pub struct Depleted(pub Timer);
@@ -0,0 +1,172 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::Collider;
+
+use crate::{
+    plugins::{
+        creature::creature_plugin::Creature,
+        farming::{
+            farming_events::{HarvestResourceEvent, ResourceHarvestedEvent},
+            resource_nodes::{Depleted, ResourceNode},
+        },
+        items::items_plugin::{EquippedItem, Item, StoreItemsEvent},
+    },
+    HARVEST_DAMAGE,
+};
+
+/// # harvest_resource_system
+/// Damage the resource node if the creature holds the required tool
+///
+/// When the node is depleted, store its yield in the creature inventory
+/// and hide the node until it respawns
+///
+pub fn harvest_resource_system(
+    mut commands: Commands,
+    creature_q: Query<&EquippedItem, With<Creature>>,
+    item_q: Query<&Item>,
+    mut node_q: Query<(&mut ResourceNode, &mut Visibility), Without<Depleted>>,
+    mut ev_harvest_resource: EventReader<HarvestResourceEvent>,
+    mut ev_store_items: EventWriter<StoreItemsEvent>,
+    mut ev_resource_harvested: EventWriter<ResourceHarvestedEvent>,
+) {
+    for ev in ev_harvest_resource.iter() {
+        let equipped_item = match creature_q.get(ev.0) {
+            Ok(creature) => creature,
+            Err(_) => continue,
+        };
+
+        let (mut node, mut visibility) = match node_q.get_mut(ev.1) {
+            Ok(node) => node,
+            Err(_) => continue,
+        };
+
+        let tool = equipped_item
+            .0
+            .and_then(|item_entity| item_q.get(item_entity).ok())
+            .map(|item| item.item_type);
+
+        let required_tool = node.node_type.required_tool();
+        if tool != Some(required_tool) {
+            println!(
+                "{:?} requires a {} to be harvested",
+                node.node_type,
+                required_tool.name()
+            );
+            continue;
+        }
+
+        node.hp -= HARVEST_DAMAGE;
+        if node.hp > 0. {
+            continue;
+        }
+
+        let (item_type, count) = node.node_type.yields();
+        ev_store_items.send(StoreItemsEvent(ev.0, item_type, count));
+
+        println!("Harvested {}x {}", count, item_type.name());
+
+        visibility.is_visible = false;
+        commands
+            .entity(ev.1)
+            .remove::<Collider>()
+            .insert(Depleted(Timer::from_seconds(
+                node.node_type.respawn_time(),
+                false,
+            )));
+
+        ev_resource_harvested.send(ResourceHarvestedEvent(ev.0, ev.1));
+    }
+}
+
+/**
+ * Restore depleted resource nodes once their respawn timer is finished
+ */
+pub fn respawn_resource_nodes_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut node_q: Query<(Entity, &mut ResourceNode, &mut Depleted, &mut Visibility)>,
+) {
+    for (entity, mut node, mut depleted, mut visibility) in node_q.iter_mut() {
+        if !depleted.0.tick(time.delta()).finished() {
+            continue;
+        }
+
+        node.hp = node.node_type.max_hp();
+        visibility.is_visible = true;
+
+        commands
+            .entity(entity)
+            .insert(node.node_type.collider())
+            .remove::<Depleted>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::asset::AssetPlugin;
+
+    use super::*;
+    use crate::plugins::{
+        farming::resource_nodes::ResourceNodeType,
+        items::{
+            items_plugin::{EquipItemEvent, Inventory, ItemType},
+            systems::equip_item::equip_item_system,
+        },
+    };
+
+    fn send<E: Send + Sync + 'static>(app: &mut App, event: E) {
+        app.world.resource_mut::<Events<E>>().send(event);
+        app.update();
+    }
+
+    #[test]
+    fn rock_is_harvested_with_the_pickaxe() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugin(AssetPlugin)
+            .add_asset::<Mesh>()
+            .add_asset::<StandardMaterial>()
+            .add_event::<EquipItemEvent>()
+            .add_event::<HarvestResourceEvent>()
+            .add_event::<StoreItemsEvent>()
+            .add_event::<ResourceHarvestedEvent>()
+            .add_system(equip_item_system)
+            .add_system(harvest_resource_system);
+
+        let axe = app.world.spawn().insert(Item::new(ItemType::Axe)).id();
+        let pickaxe = app.world.spawn().insert(Item::new(ItemType::Pickaxe)).id();
+        let player = app
+            .world
+            .spawn()
+            .insert(Creature)
+            .insert(Inventory(vec![axe, pickaxe]))
+            .insert(EquippedItem(None))
+            .id();
+        let rock = app
+            .world
+            .spawn()
+            .insert(ResourceNode::new(ResourceNodeType::Rock))
+            .insert(Visibility::default())
+            .id();
+
+        // The axe is equipped first and cannot break the rock
+        send(&mut app, EquipItemEvent(player));
+        send(&mut app, HarvestResourceEvent(player, rock));
+        let node = app.world.get::<ResourceNode>(rock).unwrap();
+        assert_eq!(node.hp, ResourceNodeType::Rock.max_hp());
+
+        // Pressing the equip key again switches to the pickaxe
+        send(&mut app, EquipItemEvent(player));
+        assert_eq!(
+            app.world.get::<EquippedItem>(player).unwrap().0,
+            Some(pickaxe)
+        );
+
+        let hits = (ResourceNodeType::Rock.max_hp() / HARVEST_DAMAGE).ceil() as usize;
+        for _ in 0..hits {
+            send(&mut app, HarvestResourceEvent(player, rock));
+        }
+
+        assert!(app.world.get::<Depleted>(rock).is_some());
+        assert!(!app.world.get::<Visibility>(rock).unwrap().is_visible);
+    }
+}
@@ -0,0 +1,2 @@
+pub mod harvest;
+pub mod spawn_nodes;
@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+
+use crate::plugins::farming::resource_nodes::{ResourceNode, ResourceNodeType};
+
+/**
+ * Spawn a resource node standing on the ground at the given position
+ */
+pub fn spawn_resource_node(
+    commands: &mut Commands,
+    mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+    node_type: ResourceNodeType,
+    ground: Vec3,
+) -> Entity {
+    let size = node_type.size();
+
+    commands
+        .spawn_bundle(PbrBundle {
+            mesh,
+            material,
+            transform: Transform::from_translation(ground + Vec3::Y * size.y / 2.),
+            ..default()
+        })
+        .insert(node_type.collider())
+        .insert(node_type.collision_groups())
+        .insert(ResourceNode::new(node_type))
+        .id()
+}
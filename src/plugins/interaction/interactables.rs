@@ -0,0 +1,93 @@
+use bevy::prelude::*;
+use rand::Rng;
+use serde::Deserialize;
+
+use crate::{plugins::items::items_plugin::ItemType, INTERACTION_RANGE};
+
+/// World object the players interact with using the interact key, within INTERACTION_RANGE
+#[derive(Component)]
+pub struct Interactable;
+
+#[derive(Component)]
+pub struct Chest {
+    pub loot: LootTable,
+    pub opened: bool,
+}
+
+/// Door blocking the way while closed, toggled by the players or by a lever
+#[derive(Component)]
+pub struct Door {
+    pub open: bool,
+    pub size: Vec3,
+}
+
+/// Lever triggering its linked entities each time it is pulled
+#[derive(Component)]
+pub struct Lever {
+    pub on: bool,
+    pub targets: Vec<Entity>,
+}
+
+/// Interactable which cannot be used until a player brings the key item, the key is consumed
+#[derive(Component)]
+pub struct Locked(pub ItemType);
+
+/// Items which may be found in a chest
+#[derive(Clone, Debug, Deserialize)]
+pub struct LootEntry {
+    pub item: ItemType,
+    #[serde(default = "default_quantity")]
+    pub min: u32,
+    #[serde(default = "default_quantity")]
+    pub max: u32,
+    /// Chance for the entry to be in the chest, from 0 to 1
+    #[serde(default = "default_chance")]
+    pub chance: f32,
+}
+
+fn default_quantity() -> u32 {
+    1
+}
+
+fn default_chance() -> f32 {
+    1.
+}
+
+/// Content of a chest, rolled when the chest is opened
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct LootTable(pub Vec<LootEntry>);
+
+impl LootTable {
+    pub fn roll(&self, rng: &mut impl Rng) -> Vec<(ItemType, u32)> {
+        let mut loot = Vec::new();
+        for entry in self.0.iter() {
+            if rng.gen::<f32>() >= entry.chance {
+                continue;
+            }
+
+            let quantity = rng.gen_range(entry.min..=entry.max.max(entry.min));
+            if quantity > 0 {
+                loot.push((entry.item, quantity));
+            }
+        }
+        loot
+    }
+}
+
+/// Nearest interactable in range of the position
+pub fn nearest_interactable<'a>(
+    interactables: impl IntoIterator<Item = (Entity, &'a GlobalTransform)>,
+    position: Vec3,
+) -> Option<Entity> {
+    interactables
+        .into_iter()
+        .map(|(entity, transform)| {
+            // Doors are tall, only the horizontal distance matters
+            let offset = transform.translation() - position;
+            (entity, Vec2::new(offset.x, offset.z).length())
+        })
+        .filter(|(_, distance)| *distance <= INTERACTION_RANGE)
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(entity, _)| entity)
+}
@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+
+/// Interact with a world object: open a chest or a door, pull a lever
+/// ### Param1 - {Entity} - The player interacting
+/// ### Param2 - {Entity} - The interactable entity
+pub struct InteractEvent(pub Entity, pub Entity);
+
+/// Activate an object linked to a lever, e.g. toggle a door
+/// ### Param1 - {Entity} - The triggered entity
+pub struct TriggerEvent(pub Entity);
@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+
+use super::{
+    interaction_events::{InteractEvent, TriggerEvent},
+    systems::{
+        interact::{interact_system, trigger_door_system},
+        prompt::{spawn_prompt_system, update_prompt_system},
+    },
+};
+
+pub struct InteractionPlugin;
+impl Plugin for InteractionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<InteractEvent>()
+            .add_event::<TriggerEvent>()
+            .add_startup_system(spawn_prompt_system)
+            .add_system(interact_system)
+            .add_system(trigger_door_system)
+            .add_system(update_prompt_system);
+    }
+}
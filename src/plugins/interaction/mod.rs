@@ -0,0 +1,4 @@
+pub mod interactables;
+pub mod interaction_events;
+pub mod interaction_plugin;
+pub mod systems;
@@ -0,0 +1,149 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::Collider;
+use rand::thread_rng;
+
+use crate::plugins::{
+    creature::creature_plugin::Player,
+    interaction::{
+        interactables::{nearest_interactable, Chest, Door, Interactable, Lever, Locked},
+        interaction_events::{InteractEvent, TriggerEvent},
+    },
+    items::items_plugin::{Belt, Inventory, Item, Quantity, StoreItemsEvent},
+};
+
+/// # interact_system
+/// Use the interactables the players interact with
+///
+/// A locked interactable consumes a key from the player inventory and is unlocked,
+/// the interaction stops there when the player has no key.
+/// Chests store their loot in the player inventory the first time they are opened,
+/// doors open or close, levers trigger their linked entities
+///
+pub fn interact_system(
+    mut commands: Commands,
+    mut player_q: Query<(&Transform, &mut Inventory, &mut Belt), With<Player>>,
+    mut key_q: Query<(&Item, Option<&mut Quantity>)>,
+    mut interactable_q: Query<
+        (
+            &GlobalTransform,
+            &mut Transform,
+            Option<&Locked>,
+            Option<&mut Chest>,
+            Option<&Door>,
+            Option<&mut Lever>,
+        ),
+        (With<Interactable>, Without<Player>),
+    >,
+    mut ev_interact: EventReader<InteractEvent>,
+    mut ev_trigger: EventWriter<TriggerEvent>,
+    mut ev_store_items: EventWriter<StoreItemsEvent>,
+) {
+    let mut rng = thread_rng();
+
+    for InteractEvent(player, target) in ev_interact.iter() {
+        let (player_transform, mut inventory, mut belt) = match player_q.get_mut(*player) {
+            Ok(player) => player,
+            Err(_) => continue,
+        };
+
+        let (global_transform, mut transform, locked, chest, door, lever) =
+            match interactable_q.get_mut(*target) {
+                Ok(interactable) => interactable,
+                Err(_) => continue,
+            };
+
+        // The player may have walked away since the key was pressed
+        if nearest_interactable([(*target, global_transform)], player_transform.translation)
+            .is_none()
+        {
+            println!("Too far to interact");
+            continue;
+        }
+
+        if let Some(Locked(key)) = locked {
+            let key_entity = match inventory.0.iter().find(|entity| match key_q.get(**entity) {
+                Ok((item, _)) => item.item_type == *key,
+                Err(_) => false,
+            }) {
+                Some(entity) => *entity,
+                None => {
+                    println!("Locked, {} required", key.name());
+                    continue;
+                }
+            };
+
+            // Take one key from the stack, remove the stack once empty
+            if let Ok((_, quantity)) = key_q.get_mut(key_entity) {
+                let remaining = match quantity {
+                    Some(mut quantity) => {
+                        quantity.0 = quantity.0.saturating_sub(1);
+                        quantity.0
+                    }
+                    None => 0,
+                };
+
+                if remaining == 0 {
+                    inventory.0.retain(|item| *item != key_entity);
+                    belt.0.retain(|item| *item != key_entity);
+                    commands.entity(key_entity).despawn_recursive();
+                }
+            }
+
+            println!("Unlocked with {}", key.name());
+            commands.entity(*target).remove::<Locked>();
+        }
+
+        if let Some(mut chest) = chest {
+            if chest.opened {
+                println!("The chest is empty");
+            } else {
+                chest.opened = true;
+                for (item_type, quantity) in chest.loot.roll(&mut rng) {
+                    ev_store_items.send(StoreItemsEvent(*player, item_type, quantity));
+                }
+            }
+        }
+
+        if door.is_some() {
+            ev_trigger.send(TriggerEvent(*target));
+        }
+
+        if let Some(mut lever) = lever {
+            lever.on = !lever.on;
+            transform.rotation = Quat::from_rotation_z(if lever.on { -0.6 } else { 0.6 });
+
+            for linked in lever.targets.iter() {
+                ev_trigger.send(TriggerEvent(*linked));
+            }
+        }
+    }
+}
+
+/**
+ * Open or close the triggered doors, an open door is hidden and lets creatures through
+ */
+pub fn trigger_door_system(
+    mut commands: Commands,
+    mut door_q: Query<(&mut Door, &mut Visibility)>,
+    mut ev_trigger: EventReader<TriggerEvent>,
+) {
+    for TriggerEvent(entity) in ev_trigger.iter() {
+        let (mut door, mut visibility) = match door_q.get_mut(*entity) {
+            Ok(door) => door,
+            Err(_) => continue,
+        };
+
+        door.open = !door.open;
+        visibility.is_visible = !door.open;
+
+        if door.open {
+            commands.entity(*entity).remove::<Collider>();
+        } else {
+            commands.entity(*entity).insert(Collider::cuboid(
+                door.size.x / 2.,
+                door.size.y / 2.,
+                door.size.z / 2.,
+            ));
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod interact;
+pub mod prompt;
+pub mod spawn_interactables;
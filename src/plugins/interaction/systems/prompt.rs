@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+
+use crate::plugins::{
+    creature::creature_plugin::Player,
+    interaction::interactables::{nearest_interactable, Chest, Door, Interactable, Lever, Locked},
+};
+
+/// Text telling the player what the interact key does
+#[derive(Component)]
+pub struct InteractionPrompt;
+
+pub fn spawn_prompt_system(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands
+        .spawn_bundle(
+            TextBundle::from_section(
+                "",
+                TextStyle {
+                    font: asset_server.load("fonts/FiraCode-Bold.ttf"),
+                    font_size: 24.,
+                    color: Color::WHITE,
+                },
+            )
+            .with_style(Style {
+                position_type: PositionType::Absolute,
+                position: UiRect {
+                    bottom: Val::Px(60.),
+                    left: Val::Percent(42.),
+                    ..default()
+                },
+                ..default()
+            }),
+        )
+        .insert(InteractionPrompt);
+}
+
+/**
+ * Show the action of the nearest interactable in range of the player,
+ * including the open doors which are hidden until they are closed
+ */
+pub fn update_prompt_system(
+    player_q: Query<&Transform, With<Player>>,
+    interactable_q: Query<(Entity, &GlobalTransform), With<Interactable>>,
+    details_q: Query<(
+        Option<&Locked>,
+        Option<&Chest>,
+        Option<&Door>,
+        Option<&Lever>,
+    )>,
+    mut prompt_q: Query<&mut Text, With<InteractionPrompt>>,
+) {
+    let nearest = player_q
+        .get_single()
+        .ok()
+        .and_then(|transform| nearest_interactable(interactable_q.iter(), transform.translation));
+
+    let prompt = match nearest.and_then(|entity| details_q.get(entity).ok()) {
+        Some((Some(Locked(key)), _, _, _)) => format!("[E] Unlock ({} required)", key.name()),
+        Some((_, Some(chest), _, _)) if chest.opened => "Empty chest".to_string(),
+        Some((_, Some(_), _, _)) => "[E] Open chest".to_string(),
+        // Open doors are hidden but stay interactable, the prompt shows where to close them
+        Some((_, _, Some(door), _)) if door.open => "[E] Close door".to_string(),
+        Some((_, _, Some(_), _)) => "[E] Open door".to_string(),
+        Some((_, _, _, Some(_))) => "[E] Pull lever".to_string(),
+        _ => String::new(),
+    };
+
+    for mut text in prompt_q.iter_mut() {
+        if text.sections[0].value != prompt {
+            text.sections[0].value = prompt.clone();
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+use crate::plugins::{
+    interaction::interactables::{Chest, Door, Interactable, Lever, LootTable},
+    world::world_plugin::WorldAssets,
+};
+
+const CHEST_SIZE: Vec3 = Vec3::new(1.2, 0.8, 0.8);
+const LEVER_SIZE: Vec3 = Vec3::new(0.2, 1.2, 0.2);
+
+/**
+ * Spawn a chest standing on the ground at the given position, an opened chest is empty
+ */
+pub fn spawn_chest(
+    commands: &mut Commands,
+    assets: &mut WorldAssets,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    loot: LootTable,
+    opened: bool,
+    transform: Transform,
+) -> Entity {
+    commands
+        .spawn_bundle(PbrBundle {
+            mesh: assets.mesh(meshes, CHEST_SIZE),
+            material: assets.material(materials, Color::rgb(0.5, 0.3, 0.1)),
+            transform: transform
+                .with_translation(transform.translation + Vec3::Y * CHEST_SIZE.y / 2.),
+            ..default()
+        })
+        .insert(Collider::cuboid(
+            CHEST_SIZE.x / 2.,
+            CHEST_SIZE.y / 2.,
+            CHEST_SIZE.z / 2.,
+        ))
+        .insert(Interactable)
+        .insert(Chest { loot, opened })
+        .id()
+}
+
+/**
+ * Spawn a door standing on the ground at the given position, open doors are hidden without collider
+ */
+pub fn spawn_door(
+    commands: &mut Commands,
+    assets: &mut WorldAssets,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    size: Vec3,
+    open: bool,
+    transform: Transform,
+) -> Entity {
+    let mut door = commands.spawn_bundle(PbrBundle {
+        mesh: assets.mesh(meshes, size),
+        material: assets.material(materials, Color::rgb(0.35, 0.25, 0.15)),
+        transform: transform.with_translation(transform.translation + Vec3::Y * size.y / 2.),
+        visibility: Visibility { is_visible: !open },
+        ..default()
+    });
+
+    if !open {
+        door.insert(Collider::cuboid(size.x / 2., size.y / 2., size.z / 2.));
+    }
+
+    door.insert(Door { open, size }).id()
+}
+
+/**
+ * Spawn a lever standing on the ground at the given position, linked to the target entities
+ */
+pub fn spawn_lever(
+    commands: &mut Commands,
+    assets: &mut WorldAssets,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    targets: Vec<Entity>,
+    ground: Vec3,
+) -> Entity {
+    commands
+        .spawn_bundle(PbrBundle {
+            mesh: assets.mesh(meshes, LEVER_SIZE),
+            material: assets.material(materials, Color::DARK_GRAY),
+            transform: Transform::from_translation(ground + Vec3::Y * LEVER_SIZE.y / 2.)
+                .with_rotation(Quat::from_rotation_z(0.6)),
+            ..default()
+        })
+        .insert(Interactable)
+        .insert(Lever { on: false, targets })
+        .id()
+}
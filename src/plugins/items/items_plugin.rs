@@ -1,22 +1,38 @@
-use crate::SWORD_SLASH_TIME;
+use crate::{
+    plugins::creature::systems::status_effects::StatusEffectType, AXE_COOLDOWN, BOMB_COOLDOWN,
+    BOW_COOLDOWN, CONSUMABLE_COOLDOWN, INVENTORY_MAX_WEIGHT, INVENTORY_SLOTS, SPEAR_COOLDOWN,
+    SWORD_SLASH_TIME, TOOL_COOLDOWN,
+};
 
 use super::{
     systems::{
-        create_items::dev_init_items_system,
-        dropped_items::dropped_items_collision_system,
+        create_items::dev_init_player_inventory_system,
+        dropped_items::{drop_item_system, dropped_items_collision_system},
         equip_item::{
             display_equiped_item, equip_item_system, pickup_item_system, unequip_item_system,
         },
-        update_items::{animate_items_system, start_items_animation_system},
+        inventory::{merge_stacks_system, split_stack_system, store_items_system},
+        update_items::{
+            animate_items_system, buffered_activation_system, charge_animation_system,
+            combo_window_system, start_items_animation_system, tick_items_cooldown_system,
+            update_belt_system,
+        },
+        use_item::{activate_item_system, charge_item_system, release_item_system},
     },
     weapons::melee::sword::slash_sword,
 };
 
 use bevy::{ecs::bundle, prelude::*};
+use bevy_rapier3d::prelude::Collider;
+use serde::Deserialize;
+use std::f32::consts::PI;
 
 // Equip an ItemBundle on a specific Entity
 pub struct EquipItemEvent(pub Entity);
 
+/// Put the equipped item of the creature back in its inventory
+pub struct UnequipItemEvent(pub Entity);
+
 /// PickUp an item from the world and equip it in the Entity inventory
 /// ### Param1 - {Entity} - The entity whom pickup the item
 /// ### Param2 - {Entity} - The item which is picked up by the entity
@@ -27,8 +43,46 @@ pub struct PickUpItemEvent(pub Entity, pub Entity);
 /// ### Param 2 - {Tranform} - The global transform to drop the item
 pub struct DropItemEvent(pub Entity, pub Transform);
 
-// Activate the equipped item of the Entity (Creature)
-pub struct ActivateItemEvent(pub Entity);
+/// Store new items in the creature inventory, merging them into existing stacks first
+///
+/// Items which do not fit are dropped on the floor next to the creature
+/// ### Param1 - {Entity} - The creature receiving the items
+/// ### Param2 - {ItemType} - The type of the items
+/// ### Param3 - {u32} - The quantity of items
+pub struct StoreItemsEvent(pub Entity, pub ItemType, pub u32);
+
+/// Feedback sent when items cannot fit in the creature inventory
+/// ### Param1 - {Entity} - The creature whose inventory is full
+/// ### Param2 - {ItemType} - The type of the refused items
+/// ### Param3 - {u32} - The quantity of refused items
+pub struct InventoryFullEvent(pub Entity, pub ItemType, pub u32);
+
+/// Move as many items as possible from a stack to another stack of the same type
+/// ### Param1 - {Entity} - The creature owning both stacks
+/// ### Param2 - {Entity} - The stack to take items from
+/// ### Param3 - {Entity} - The stack receiving the items
+pub struct MergeStacksEvent(pub Entity, pub Entity, pub Entity);
+
+/// Move some items of a stack to a new inventory slot
+/// ### Param1 - {Entity} - The creature owning the stack
+/// ### Param2 - {Entity} - The stack to split
+/// ### Param3 - {u32} - The quantity moved to the new stack
+pub struct SplitStackEvent(pub Entity, pub Entity, pub u32);
+
+/// Activate an item of the creature (equipped item or belt item)
+/// ### Param1 - {Entity} - The creature using the item
+/// ### Param2 - {Entity} - The item entity
+pub struct ActivateItemEvent(pub Entity, pub Entity);
+
+/// Start charging a heavy attack with the item, items without heavy attack are activated
+/// ### Param1 - {Entity} - The creature holding the item
+/// ### Param2 - {Entity} - The item entity
+pub struct ChargeItemEvent(pub Entity, pub Entity);
+
+/// Release the charged item, a full charge triggers the heavy attack, a light one otherwise
+/// ### Param1 - {Entity} - The creature holding the item
+/// ### Param2 - {Entity} - The item entity
+pub struct ReleaseItemEvent(pub Entity, pub Entity);
 
 #[derive(Component)]
 pub struct Pickable;
@@ -38,65 +92,523 @@ impl Plugin for ItemsPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<PickUpItemEvent>()
             .add_event::<EquipItemEvent>()
+            .add_event::<UnequipItemEvent>()
             .add_event::<ActivateItemEvent>()
-            .add_startup_system(dev_init_items_system)
+            .add_event::<ChargeItemEvent>()
+            .add_event::<ReleaseItemEvent>()
+            .add_event::<DropItemEvent>()
+            .add_event::<StoreItemsEvent>()
+            .add_event::<InventoryFullEvent>()
+            .add_event::<MergeStacksEvent>()
+            .add_event::<SplitStackEvent>()
+            .add_startup_system_to_stage(
+                StartupStage::PostStartup,
+                dev_init_player_inventory_system,
+            )
             .add_system(pickup_item_system)
             .add_system(equip_item_system)
             .add_system(unequip_item_system)
             .add_system(start_items_animation_system)
             .add_system(display_equiped_item)
             .add_system(dropped_items_collision_system)
-            .add_system(animate_items_system);
+            .add_system(animate_items_system)
+            .add_system(tick_items_cooldown_system)
+            .add_system(update_belt_system)
+            .add_system(activate_item_system)
+            .add_system(buffered_activation_system)
+            .add_system(charge_item_system)
+            .add_system(release_item_system)
+            .add_system(charge_animation_system)
+            .add_system(combo_window_system)
+            .add_system(store_items_system)
+            .add_system(merge_stacks_system)
+            .add_system(split_stack_system)
+            .add_system(drop_item_system);
     }
 }
 
 #[derive(Default, Component)]
 pub struct ItemMesh;
 
-#[derive(Default, Component)]
+#[derive(Clone, Copy, Debug, Default, Component, PartialEq, Eq, Hash, Deserialize)]
 pub enum ItemType {
     #[default]
     Sword,
+    Spear,
+    // Natural weapon of the monsters
+    Claws,
+    Bow,
     Shovel,
+    Axe,
+    Pickaxe,
+    Hammer,
+    // Materials
+    Wood,
+    Stone,
+    IronOre,
+    IronIngot,
+    Fiber,
+    // Ammunitions
+    Arrow,
+    // Consumables
+    HealingPotion,
+    CookedMeal,
+    Bomb,
+    Antidote,
+    Bandage,
+    // Opens the locked chests and doors
+    Key,
 }
 
 impl ItemType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ItemType::Sword => "Sword",
+            ItemType::Spear => "Spear",
+            ItemType::Claws => "Claws",
+            ItemType::Bow => "Bow",
+            ItemType::Shovel => "Shovel",
+            ItemType::Axe => "Axe",
+            ItemType::Pickaxe => "Pickaxe",
+            ItemType::Hammer => "Hammer",
+            ItemType::Wood => "Wood",
+            ItemType::Stone => "Stone",
+            ItemType::IronOre => "Iron ore",
+            ItemType::IronIngot => "Iron ingot",
+            ItemType::Fiber => "Fiber",
+            ItemType::Arrow => "Arrow",
+            ItemType::HealingPotion => "Healing potion",
+            ItemType::CookedMeal => "Cooked meal",
+            ItemType::Bomb => "Bomb",
+            ItemType::Antidote => "Antidote",
+            ItemType::Bandage => "Bandage",
+            ItemType::Key => "Key",
+        }
+    }
+
     pub fn dimensions(&self) -> Vec3 {
         match self {
             ItemType::Sword => Vec3::new(0.2, 1.3, 0.2),
-            ItemType::Shovel => Vec3::new(0.2, 1.3, 0.2),
+            ItemType::Spear => Vec3::new(0.15, 2.4, 0.15),
+            ItemType::Claws => Vec3::new(0.3, 0.3, 0.3),
+            ItemType::Bow => Vec3::new(0.1, 1.4, 0.3),
+            ItemType::Shovel | ItemType::Axe | ItemType::Pickaxe => Vec3::new(0.4, 1.3, 0.4),
+            ItemType::Hammer => Vec3::new(0.4, 0.8, 0.4),
+            ItemType::Wood => Vec3::new(0.3, 0.9, 0.3),
+            ItemType::Stone | ItemType::IronOre | ItemType::IronIngot => Vec3::new(0.4, 0.3, 0.4),
+            ItemType::Fiber => Vec3::new(0.1, 0.6, 0.1),
+            ItemType::Arrow => Vec3::new(0.05, 0.8, 0.05),
+            ItemType::HealingPotion => Vec3::new(0.2, 0.4, 0.2),
+            ItemType::CookedMeal => Vec3::new(0.4, 0.2, 0.4),
+            ItemType::Bomb => Vec3::new(0.4, 0.4, 0.4),
+            ItemType::Antidote => Vec3::new(0.2, 0.4, 0.2),
+            ItemType::Bandage => Vec3::new(0.3, 0.1, 0.3),
+            ItemType::Key => Vec3::new(0.1, 0.4, 0.05),
         }
     }
 
     pub fn mesh(&self) -> Mesh {
-        match self {
-            ItemType::Sword => Mesh::from(shape::Box::new(0.2, 1.3, 0.2)),
-            ItemType::Shovel => Mesh::from(shape::Box::new(0.4, 1.3, 0.4)),
-        }
+        let dimensions = self.dimensions();
+        Mesh::from(shape::Box::new(dimensions.x, dimensions.y, dimensions.z))
     }
 
     pub fn color(&self) -> Color {
         match self {
             ItemType::Sword => Color::PURPLE,
+            ItemType::Spear => Color::INDIGO,
+            ItemType::Claws => Color::ANTIQUE_WHITE,
+            ItemType::Bow => Color::rgb(0.45, 0.25, 0.1),
             ItemType::Shovel => Color::SEA_GREEN,
+            ItemType::Axe => Color::ORANGE_RED,
+            ItemType::Pickaxe => Color::SILVER,
+            ItemType::Hammer => Color::MAROON,
+            ItemType::Wood => Color::rgb(0.55, 0.35, 0.15),
+            ItemType::Stone => Color::GRAY,
+            ItemType::IronOre => Color::rgb(0.45, 0.3, 0.3),
+            ItemType::IronIngot => Color::rgb(0.8, 0.8, 0.85),
+            ItemType::Fiber => Color::YELLOW_GREEN,
+            ItemType::Arrow => Color::BLUE,
+            ItemType::HealingPotion => Color::CRIMSON,
+            ItemType::CookedMeal => Color::ORANGE,
+            ItemType::Bomb => Color::BLACK,
+            ItemType::Antidote => Color::LIME_GREEN,
+            ItemType::Bandage => Color::WHITE,
+            ItemType::Key => Color::GOLD,
         }
     }
 
-    pub fn animation_timer(&self) -> AnimationTimer {
+    /// Items which hit creatures when activated
+    pub fn is_weapon(&self) -> bool {
+        matches!(
+            self,
+            ItemType::Sword | ItemType::Spear | ItemType::Claws | ItemType::Axe
+        )
+    }
+
+    /// Items which harvest resource nodes when activated
+    pub fn is_tool(&self) -> bool {
+        matches!(
+            self,
+            ItemType::Shovel | ItemType::Axe | ItemType::Pickaxe | ItemType::Hammer
+        )
+    }
+
+    /// Items drawn with the right click, firing projectiles
+    pub fn is_ranged(&self) -> bool {
+        matches!(self, ItemType::Bow)
+    }
+
+    /// Ammunition consumed from the inventory when firing a ranged item
+    pub fn ammo(&self) -> Option<ItemType> {
+        match self {
+            ItemType::Bow => Some(ItemType::Arrow),
+            _ => None,
+        }
+    }
+
+    pub fn is_equipable(&self) -> bool {
+        self.is_weapon() || self.is_tool() || self.is_ranged()
+    }
+
+    /// Effect applied when the item is used from the belt
+    pub fn consumable_effect(&self) -> Option<ItemEffect> {
+        match self {
+            ItemType::HealingPotion => Some(ItemEffect::Heal(40.)),
+            ItemType::CookedMeal => Some(ItemEffect::Buff {
+                atk: 5.,
+                duration: 30.,
+            }),
+            ItemType::Bomb => Some(ItemEffect::Explode {
+                damage: 50.,
+                radius: 5.,
+            }),
+            ItemType::Antidote => Some(ItemEffect::Cure {
+                effect: StatusEffectType::Poison,
+                immunity: 20.,
+            }),
+            ItemType::Bandage => Some(ItemEffect::Status(StatusEffectType::Regeneration)),
+            _ => None,
+        }
+    }
+
+    pub fn is_consumable(&self) -> bool {
+        self.consumable_effect().is_some()
+    }
+
+    /// Maximum quantity of items in a single inventory slot
+    pub fn max_stack(&self) -> u32 {
+        match self {
+            ItemType::Wood
+            | ItemType::Stone
+            | ItemType::IronOre
+            | ItemType::IronIngot
+            | ItemType::Fiber => 20,
+            ItemType::Arrow => 30,
+            ItemType::HealingPotion
+            | ItemType::CookedMeal
+            | ItemType::Bomb
+            | ItemType::Antidote
+            | ItemType::Bandage => 5,
+            _ => 1,
+        }
+    }
+
+    pub fn weight(&self) -> f32 {
+        match self {
+            ItemType::Sword | ItemType::Axe | ItemType::Pickaxe => 4.,
+            ItemType::Spear => 3.5,
+            ItemType::Claws => 0.,
+            ItemType::Shovel | ItemType::Hammer => 3.,
+            ItemType::Bow => 2.,
+            ItemType::Stone | ItemType::IronOre => 2.,
+            ItemType::IronIngot | ItemType::Bomb => 1.5,
+            ItemType::Wood => 1.,
+            ItemType::HealingPotion | ItemType::CookedMeal | ItemType::Antidote => 0.5,
+            ItemType::Fiber | ItemType::Arrow | ItemType::Bandage | ItemType::Key => 0.1,
+        }
+    }
+
+    /// Number of uses before a consumable is destroyed
+    pub fn max_charges(&self) -> Option<u32> {
+        match self {
+            ItemType::HealingPotion => Some(1),
+            ItemType::CookedMeal => Some(2),
+            ItemType::Bomb => Some(1),
+            ItemType::Antidote | ItemType::Bandage => Some(1),
+            _ => None,
+        }
+    }
+
+    /// Part of the swing animation, as a fraction of its duration, during which the item hits
+    pub fn active_frames(&self) -> (f32, f32) {
+        match self {
+            ItemType::Sword | ItemType::Claws => (0.25, 0.6),
+            ItemType::Spear => (0.45, 0.65),
+            ItemType::Axe => (0.35, 0.7),
+            _ => (0.4, 0.75),
+        }
+    }
+
+    /// Knockback speed of a hit, for a victim of KNOCKBACK_REFERENCE_MASS
+    pub fn knockback(&self) -> f32 {
         match self {
-            ItemType::Sword => AnimationTimer(Timer::from_seconds(SWORD_SLASH_TIME, false)),
-            ItemType::Shovel => AnimationTimer(Timer::from_seconds(SWORD_SLASH_TIME, false)),
+            ItemType::Sword => 6.,
+            ItemType::Spear => 9.,
+            ItemType::Claws => 7.,
+            ItemType::Axe => 8.,
+            _ => 4.,
         }
     }
 
+    /// Seconds during which the victim of a hit can neither move nor attack
+    pub fn stagger_time(&self) -> f32 {
+        match self {
+            ItemType::Sword => 0.4,
+            ItemType::Spear | ItemType::Claws => 0.5,
+            ItemType::Axe => 0.6,
+            _ => 0.3,
+        }
+    }
+
+    /// Stamina spent by a swing, or by a shot for ranged items
+    pub fn stamina_cost(&self) -> f32 {
+        match self {
+            ItemType::Sword => 15.,
+            ItemType::Spear => 18.,
+            ItemType::Claws => 12.,
+            ItemType::Axe => 20.,
+            ItemType::Bow => 10.,
+            ItemType::Shovel | ItemType::Pickaxe | ItemType::Hammer => 10.,
+            _ => 0.,
+        }
+    }
+
+    /// Number of distinct swings chained by consecutive activations
+    pub fn combo_length(&self) -> usize {
+        match self {
+            ItemType::Sword => 3,
+            ItemType::Spear | ItemType::Axe => 2,
+            _ => 1,
+        }
+    }
+
+    /// Weapons charging a heavy attack while the activation is held
+    pub fn has_heavy_attack(&self) -> bool {
+        matches!(self, ItemType::Sword | ItemType::Spear | ItemType::Axe)
+    }
+
+    /// Swing played for a step of the combo or for the heavy attack
+    pub fn attack(&self, kind: AttackKind) -> Attack {
+        let base = Attack {
+            arc: Quat::from_rotation_x(2.),
+            damage: 1.,
+            knockback: 1.,
+            stamina: 1.,
+            hitbox: self.hitbox(),
+        };
+
+        match (self, kind) {
+            // Horizontal sweep then overhead finisher
+            (ItemType::Sword, AttackKind::Combo(1)) => Attack {
+                arc: Quat::from_rotation_y(-2.),
+                knockback: 0.8,
+                hitbox: Some(Hitbox {
+                    collider: Collider::cuboid(1., 0.5, 2.5),
+                    transform: Transform::from_xyz(1.5, 0., 0.),
+                }),
+                ..base
+            },
+            (ItemType::Sword, AttackKind::Combo(2)) => Attack {
+                arc: Quat::from_rotation_x(2.6),
+                damage: 1.5,
+                knockback: 1.5,
+                stamina: 1.2,
+                hitbox: Some(Hitbox {
+                    collider: Collider::cuboid(2., 0.5, 0.8),
+                    transform: Transform::from_xyz(2., 0., 0.),
+                }),
+            },
+            (ItemType::Sword, AttackKind::Heavy) => Attack {
+                arc: Quat::from_rotation_x(3.),
+                damage: 2.2,
+                knockback: 2.5,
+                stamina: 2.,
+                hitbox: Some(Hitbox {
+                    collider: Collider::ball(2.5),
+                    transform: Transform::from_xyz(1.5, 0., 0.),
+                }),
+            },
+            // Short thrust then wide sweep with the shaft
+            (ItemType::Spear, AttackKind::Combo(0)) => Attack {
+                arc: Quat::from_rotation_x(1.2),
+                ..base
+            },
+            (ItemType::Spear, AttackKind::Combo(1)) => Attack {
+                arc: Quat::from_rotation_y(-2.),
+                damage: 0.8,
+                knockback: 1.2,
+                hitbox: Some(Hitbox {
+                    collider: Collider::cuboid(1.2, 0.4, 2.5),
+                    transform: Transform::from_xyz(1.5, 0., 0.),
+                }),
+                ..base
+            },
+            (ItemType::Spear, AttackKind::Heavy) => Attack {
+                arc: Quat::from_rotation_x(1.5),
+                damage: 2.,
+                knockback: 2.5,
+                stamina: 2.,
+                hitbox: Some(Hitbox {
+                    collider: Collider::cuboid(3., 0.4, 0.4),
+                    transform: Transform::from_xyz(3.2, 0., 0.),
+                }),
+            },
+            // Side chop then overhead chop
+            (ItemType::Axe, AttackKind::Combo(1)) => Attack {
+                arc: Quat::from_rotation_x(2.8),
+                damage: 1.4,
+                knockback: 1.3,
+                stamina: 1.2,
+                hitbox: Some(Hitbox {
+                    collider: Collider::cuboid(1., 0.5, 0.8),
+                    transform: Transform::from_xyz(1.4, 0., 0.),
+                }),
+            },
+            // Spin hitting all around the creature
+            (ItemType::Axe, AttackKind::Heavy) => Attack {
+                arc: Quat::from_rotation_y(-3.),
+                damage: 2.,
+                knockback: 2.,
+                stamina: 2.,
+                hitbox: Some(Hitbox {
+                    collider: Collider::ball(2.5),
+                    transform: Transform::default(),
+                }),
+            },
+            _ => base,
+        }
+    }
+
+    /// Status effect applied to the creatures hit
+    pub fn on_hit_status(&self) -> Option<StatusEffectType> {
+        match self {
+            ItemType::Spear | ItemType::Axe => Some(StatusEffectType::Bleed),
+            ItemType::Claws => Some(StatusEffectType::Poison),
+            _ => None,
+        }
+    }
+
+    /// Sensor spawned on the creature holding the item, relative to the creature
+    pub fn hitbox(&self) -> Option<Hitbox> {
+        let hitbox = match self {
+            // Wide cone in front of the creature
+            ItemType::Sword => Hitbox {
+                collider: Collider::cone(2., 3.),
+                transform: Transform {
+                    translation: Vec3::new(1.5, 0., 0.),
+                    rotation: Quat::from_rotation_z(PI / 2.),
+                    ..default()
+                },
+            },
+            // Long and narrow thrust
+            ItemType::Spear => Hitbox {
+                collider: Collider::cuboid(2.25, 0.4, 0.4),
+                transform: Transform::from_xyz(2.5, 0., 0.),
+            },
+            // Monsters do not turn to face their target, claws reach all around them
+            ItemType::Claws => Hitbox {
+                collider: Collider::ball(2.),
+                transform: Transform::default(),
+            },
+            // Short and wide arc
+            ItemType::Axe => Hitbox {
+                collider: Collider::cuboid(0.8, 0.5, 1.5),
+                transform: Transform::from_xyz(1.3, 0., 0.),
+            },
+            ItemType::Shovel | ItemType::Pickaxe | ItemType::Hammer => Hitbox {
+                collider: Collider::ball(1.),
+                transform: Transform::from_xyz(1.3, 0., 0.),
+            },
+            _ => return None,
+        };
+
+        Some(hitbox)
+    }
+
+    pub fn animation_timer(&self) -> AnimationTimer {
+        AnimationTimer(Timer::from_seconds(SWORD_SLASH_TIME, false))
+    }
+
     pub fn cooldown_timer(&self) -> ActivationTimer {
         match self {
-            ItemType::Sword => ActivationTimer(Timer::from_seconds(SWORD_SLASH_TIME, false)),
-            ItemType::Shovel => ActivationTimer(Timer::from_seconds(SWORD_SLASH_TIME, false)),
+            ItemType::HealingPotion
+            | ItemType::CookedMeal
+            | ItemType::Antidote
+            | ItemType::Bandage => ActivationTimer(Timer::from_seconds(CONSUMABLE_COOLDOWN, false)),
+            ItemType::Bomb => ActivationTimer(Timer::from_seconds(BOMB_COOLDOWN, false)),
+            ItemType::Spear => ActivationTimer(Timer::from_seconds(SPEAR_COOLDOWN, false)),
+            ItemType::Axe => ActivationTimer(Timer::from_seconds(AXE_COOLDOWN, false)),
+            ItemType::Bow => ActivationTimer(Timer::from_seconds(BOW_COOLDOWN, false)),
+            ItemType::Shovel | ItemType::Pickaxe | ItemType::Hammer => {
+                ActivationTimer(Timer::from_seconds(TOOL_COOLDOWN, false))
+            }
+            _ => ActivationTimer(Timer::from_seconds(SWORD_SLASH_TIME, false)),
         }
     }
 }
 
+/// Shape and offset of the sensor hit by a weapon or a tool
+pub struct Hitbox {
+    pub collider: Collider,
+    pub transform: Transform,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AttackKind {
+    /// Step of the combo, starting at 0
+    Combo(usize),
+    /// Attack released after a full charge
+    Heavy,
+}
+
+/// A swing of a weapon or a tool, the multipliers apply to the item values
+pub struct Attack {
+    /// Rotation of the item at the end of the swing
+    pub arc: Quat,
+    pub damage: f32,
+    pub knockback: f32,
+    pub stamina: f32,
+    pub hitbox: Option<Hitbox>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum ItemEffect {
+    Heal(f32),
+    Buff {
+        atk: f32,
+        duration: f32,
+    },
+    Explode {
+        damage: f32,
+        radius: f32,
+    },
+    /// Apply a status effect to the creature using the item
+    Status(StatusEffectType),
+    /// Remove a status effect and prevent it for the immunity duration
+    Cure {
+        effect: StatusEffectType,
+        immunity: f32,
+    },
+}
+
+/// Remaining uses of a consumable item, restored when the next item of the stack is used
+#[derive(Component)]
+pub struct Charges(pub u32);
+
+/// Number of items in a stack
+#[derive(Component)]
+pub struct Quantity(pub u32);
+
 #[derive(Default, Component)]
 pub struct ActivationTimer(pub Timer);
 
@@ -133,13 +645,70 @@ pub struct DroppedItem {
 #[derive(Component, Default)]
 pub struct AnimateVisualItem;
 
+/// Attack played by the current swing and the entities it already hit,
+/// each one is hit once per swing
+#[derive(Component)]
+pub struct Swing {
+    pub attack: AttackKind,
+    pub hit: Vec<Entity>,
+}
+
+impl Swing {
+    pub fn new(attack: AttackKind) -> Self {
+        Self {
+            attack,
+            hit: Vec::new(),
+        }
+    }
+}
+
+/// Step of the last combo swing, the next activation chains into the following step
+/// if it comes before the window timer finishes
+#[derive(Component)]
+pub struct Combo {
+    pub step: usize,
+    pub window: Timer,
+}
+
+/// Heavy attack charging while the activation is held, ready once the timer is finished
+#[derive(Component)]
+pub struct ChargingAttack(pub Timer);
+
+/// Activation requested while the item was busy, replayed as soon as it is ready
+/// unless the timer runs out first
+#[derive(Component)]
+pub struct BufferedActivation(pub Timer);
+
 impl Item {
+    pub fn new(item_type: ItemType) -> Self {
+        let mut cooldown_timer = item_type.cooldown_timer();
+
+        // Items are ready to be used as soon as they are created
+        let duration = cooldown_timer.0.duration();
+        cooldown_timer.0.tick(duration);
+
+        Self {
+            item_type,
+            cooldown_timer,
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.cooldown_timer.0.finished()
+    }
+
+    /// Seconds left before the item can be activated again
+    pub fn remaining_cooldown(&self) -> f32 {
+        let timer = &self.cooldown_timer.0;
+        (timer.duration().as_secs_f32() - timer.elapsed_secs()).max(0.)
+    }
+
     fn primary(&mut self) -> () {
         self.cooldown_timer.0.reset();
         // insert Animate Component
     }
 
-    fn activate(&mut self) -> () {
+    pub fn activate(&mut self) -> () {
         self.cooldown_timer.0.reset();
     }
 }
@@ -153,6 +722,7 @@ impl UpdateItem for Item {
         match self.item_type {
             ItemType::Sword => slash_sword(&self, time),
             ItemType::Shovel => slash_sword(&self, time),
+            _ => (),
         }
     }
 }
@@ -168,6 +738,7 @@ impl EquipItem for Item {
     }
 }
 
+/// The Item entity held by the creature, also used as its visual item
 #[derive(Component, Default)]
 pub struct EquippedItem(pub Option<Entity>);
 
@@ -175,6 +746,7 @@ pub struct EquippedItem(pub Option<Entity>);
 pub struct InventoryBundle {
     inventory: Inventory,
     belt: Belt,
+    capacity: InventoryCapacity,
 }
 
 impl InventoryBundle {
@@ -182,6 +754,7 @@ impl InventoryBundle {
         Self {
             inventory: Inventory(Vec::new()),
             belt: Belt(Vec::new()),
+            capacity: InventoryCapacity::default(),
         }
     }
 
@@ -193,3 +766,18 @@ pub struct Inventory(pub Vec<Entity>);
 
 #[derive(Component, Default)]
 pub struct Belt(pub Vec<Entity>);
+
+#[derive(Component)]
+pub struct InventoryCapacity {
+    pub slots: usize,
+    pub max_weight: f32,
+}
+
+impl Default for InventoryCapacity {
+    fn default() -> Self {
+        Self {
+            slots: INVENTORY_SLOTS,
+            max_weight: INVENTORY_MAX_WEIGHT,
+        }
+    }
+}
@@ -1,55 +1,45 @@
-use std::f32::consts::PI;
-
 use bevy::prelude::*;
-use bevy_rapier3d::prelude::{ActiveEvents, Collider, CollisionGroups, Group, Sensor};
 
-use crate::{
-    plugins::{
-        creature::creature_plugin::Player,
-        items::items_plugin::{Item, ItemType, PickUpItemEvent, Pickable},
-    },
-    MONSTER_GROUP, PICKABLE_GROUP, PLAYER_GROUP,
+use crate::plugins::{
+    creature::creature_plugin::Player,
+    items::items_plugin::{Charges, Item, ItemType, Quantity, StoreItemsEvent},
 };
 
 /**
- * DEV system used to populate the game with some items to equip
+ * DEV system used to give the player some starting tools
  */
-pub fn dev_init_items_system(
-    mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+pub fn dev_init_player_inventory_system(
+    player_q: Query<Entity, With<Player>>,
+    mut ev_store_items: EventWriter<StoreItemsEvent>,
 ) {
-    // Create Item
-    let mut item_entity = commands.spawn();
-    item_entity.insert(Item {
-        item_type: ItemType::Sword,
-        ..default()
-    });
+    let player = match player_q.get_single() {
+        Ok(player) => player,
+        Err(_) => return,
+    };
+
+    for (item_type, quantity) in [
+        (ItemType::Axe, 1),
+        (ItemType::Pickaxe, 1),
+        (ItemType::Bow, 1),
+        (ItemType::Arrow, 15),
+        (ItemType::HealingPotion, 2),
+        (ItemType::CookedMeal, 1),
+        (ItemType::Bomb, 1),
+    ] {
+        ev_store_items.send(StoreItemsEvent(player, item_type, quantity));
+    }
+}
 
-    // DropItemEvent
-    item_entity.insert_bundle(SpatialBundle {
-        transform: Transform::from_xyz(0., 0.5, 0.),
-        ..default()
-    });
+/**
+ * Spawn a stack of items that is not placed in the world, e.g. to store it directly in an inventory
+ */
+pub fn spawn_item(commands: &mut Commands, item_type: ItemType, quantity: u32) -> Entity {
+    let mut item = commands.spawn();
+    item.insert(Item::new(item_type)).insert(Quantity(quantity));
 
-    item_entity.add_children(|parent| {
-        let mut transform = Transform::from_xyz(1., 0., 0.5);
-        transform.rotate_z(PI / 2.);
+    if let Some(charges) = item_type.max_charges() {
+        item.insert(Charges(charges));
+    }
 
-        parent
-            .spawn_bundle(PbrBundle {
-                mesh: meshes.add(ItemType::Sword.mesh()),
-                material: materials.add(ItemType::Sword.color().into()),
-                transform,
-                ..default()
-            })
-            .insert(Collider::capsule(Vec3::Y / 2., Vec3::ZERO, 1.))
-            .insert(Sensor)
-            .insert(Pickable)
-            .insert(ActiveEvents::COLLISION_EVENTS)
-            .insert(CollisionGroups::new(
-                PICKABLE_GROUP,
-                PLAYER_GROUP | MONSTER_GROUP,
-            ));
-    });
+    item.id()
 }
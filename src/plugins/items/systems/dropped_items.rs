@@ -1,7 +1,75 @@
-use bevy::prelude::*;
+use std::f32::consts::PI;
+
+use bevy::{ecs::system::EntityCommands, prelude::*};
 use bevy_rapier3d::prelude::*;
 
-use crate::plugins::items::items_plugin::{PickUpItemEvent, Pickable};
+use crate::{
+    plugins::items::items_plugin::{DropItemEvent, Item, ItemType, PickUpItemEvent, Pickable},
+    MONSTER_GROUP, PICKABLE_GROUP, PLAYER_GROUP,
+};
+
+/**
+ * Place an item entity in the world with a pickable sensor
+ */
+pub fn insert_dropped_item_bundle(
+    cmds: &mut EntityCommands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    item_type: ItemType,
+    transform: Transform,
+) {
+    cmds.insert_bundle(SpatialBundle {
+        transform,
+        ..default()
+    });
+
+    cmds.add_children(|parent| {
+        let mut transform = Transform::from_xyz(1., 0., 0.5);
+        transform.rotate_z(PI / 2.);
+
+        parent
+            .spawn_bundle(PbrBundle {
+                mesh: meshes.add(item_type.mesh()),
+                material: materials.add(item_type.color().into()),
+                transform,
+                ..default()
+            })
+            .insert(Collider::capsule(Vec3::Y / 2., Vec3::ZERO, 1.))
+            .insert(Sensor)
+            .insert(Pickable)
+            .insert(ActiveEvents::COLLISION_EVENTS)
+            .insert(CollisionGroups::new(
+                PICKABLE_GROUP,
+                PLAYER_GROUP | MONSTER_GROUP,
+            ));
+    });
+}
+
+/**
+ * Receive a DropItemEvent and place the item on the floor
+ */
+pub fn drop_item_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    item_q: Query<&Item>,
+    mut ev_drop_item: EventReader<DropItemEvent>,
+) {
+    for ev in ev_drop_item.iter() {
+        let item = match item_q.get(ev.0) {
+            Ok(item) => item,
+            Err(_) => continue,
+        };
+
+        insert_dropped_item_bundle(
+            &mut commands.entity(ev.0),
+            &mut meshes,
+            &mut materials,
+            item.item_type,
+            ev.1,
+        );
+    }
+}
 
 pub fn dropped_items_collision_system(
     mut commands: Commands,
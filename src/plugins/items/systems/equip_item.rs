@@ -1,18 +1,45 @@
 use crate::plugins::{
     creature::creature_plugin::Creature,
-    items::items_plugin::{
-        EquipItemEvent, EquippedItem, Inventory, Item, ItemMesh, ItemType, PickUpItemEvent,
-        VisualItem,
+    items::{
+        items_plugin::{
+            AnimateVisualItem, BufferedActivation, ChargingAttack, Combo, EquipItemEvent,
+            EquippedItem, Inventory, InventoryCapacity, InventoryFullEvent, Item, ItemMesh,
+            ItemType, PickUpItemEvent, Pickable, Quantity, StoreItemsEvent, Swing,
+            UnequipItemEvent, VisualItem,
+        },
+        systems::inventory::{stack_of, InventoryStack},
     },
 };
 use bevy::prelude::*;
+use std::collections::HashMap;
+
+/**
+ * Detach the equipped item from the creature, the item stays in the inventory
+ * and its attack in progress is dropped
+ */
+fn unequip_item(commands: &mut Commands, creature: Entity, equipped_item: &mut EquippedItem) {
+    let item = match equipped_item.0.take() {
+        Some(item) => item,
+        None => return,
+    };
+
+    commands.entity(creature).remove_children(&[item]);
+    commands
+        .entity(item)
+        .remove_bundle::<VisualItem>()
+        .remove::<ItemMesh>()
+        .remove::<AnimateVisualItem>()
+        .remove::<Swing>()
+        .remove::<ChargingAttack>()
+        .remove::<Combo>()
+        .remove::<BufferedActivation>();
+}
 
 /// # equip_item_system
-/// Get the bundle item from available item bundles  
-///
-/// Add Equipped component to this item bundle  
+/// Equip the next equipable item of the inventory, after the equipped one
 ///
-/// Remove Equiped component from previous equiped item bundle  
+/// The equipped item is unequipped first, pressing the equip key again
+/// cycles through the weapons and tools of the inventory
 ///
 pub fn equip_item_system(
     mut commands: Commands,
@@ -23,25 +50,44 @@ pub fn equip_item_system(
     mut materials: ResMut<Assets<StandardMaterial>>,
 ) {
     for ev in ev_equip_item.iter() {
-        let (creature_entity, inventory, mut equipped_item) =
-            creature_q.get_mut(ev.0).expect("No creature found");
-
-        println!("inventory found");
+        let (creature_entity, inventory, mut equipped_item) = match creature_q.get_mut(ev.0) {
+            Ok(creature) => creature,
+            Err(_) => continue,
+        };
 
-        let item_entity = inventory.0.first().take().unwrap();
-        let item = item_q
-            .get(item_entity.clone())
-            .expect("Cant find given item entity");
+        // Start looking after the equipped item, wrapping around the inventory
+        let start = equipped_item
+            .0
+            .and_then(|equipped| inventory.0.iter().position(|entity| *entity == equipped))
+            .map_or(0, |position| position + 1);
+
+        let (item_entity, item) = match inventory
+            .0
+            .iter()
+            .cycle()
+            .skip(start)
+            .take(inventory.0.len())
+            .find_map(|entity| match item_q.get(*entity) {
+                Ok(item) if item.item_type.is_equipable() => Some((*entity, item)),
+                _ => None,
+            }) {
+            Some(tuple) => tuple,
+            None => {
+                println!("No item to equip");
+                continue;
+            }
+        };
 
-        if let Some(mut item) = equipped_item.0 {
-            println!("Item already equipped");
+        if equipped_item.0 == Some(item_entity) {
             continue;
         }
 
-        // Add EquippedItem component to inventory with Item entity reference
-        // And spawn pbr bundle
-        commands.entity(creature_entity).add_children(|parent| {
-            let mut child = parent.spawn_bundle(VisualItem {
+        unequip_item(&mut commands, creature_entity, &mut equipped_item);
+
+        // The item entity becomes the visual item, attached to the creature
+        commands
+            .entity(item_entity)
+            .insert_bundle(VisualItem {
                 mesh: PbrBundle {
                     mesh: meshes.add(item.item_type.mesh()),
                     material: materials.add(item.item_type.color().into()),
@@ -49,19 +95,31 @@ pub fn equip_item_system(
                     ..default()
                 },
                 animation_timer: item.item_type.animation_timer(),
-            });
+            })
+            .insert(ItemMesh);
 
-            child.insert(ItemMesh);
+        commands
+            .entity(creature_entity)
+            .push_children(&[item_entity]);
 
-            equipped_item.0 = Some(child.id());
-        });
+        equipped_item.0 = Some(item_entity);
     }
 }
 
-/// # unequip_item_system
-///
-///
-pub fn unequip_item_system() {}
+/**
+ * Put the equipped item of the creature back in its inventory
+ */
+pub fn unequip_item_system(
+    mut commands: Commands,
+    mut creature_q: Query<&mut EquippedItem, With<Creature>>,
+    mut ev_unequip_item: EventReader<UnequipItemEvent>,
+) {
+    for ev in ev_unequip_item.iter() {
+        if let Ok(mut equipped_item) = creature_q.get_mut(ev.0) {
+            unequip_item(&mut commands, ev.0, &mut equipped_item);
+        }
+    }
+}
 
 /**
  * Display the equipped bundle item on the creature
@@ -90,31 +148,76 @@ pub fn display_equiped_item(
 
 /**
  * Receive a PickUpItem event and place item into entity inventory
+ *
+ * Only the quantity fitting in the inventory is picked up, the rest stays on the floor.
+ * The stores are applied later by the store_items_system, the stacks planned by this run
+ * are kept so the pickups of the same frame do not count the same free space twice
  */
 pub fn pickup_item_system(
-    mut inventory_q: Query<&mut Inventory, With<Creature>>,
+    mut commands: Commands,
+    creature_q: Query<(&Inventory, &InventoryCapacity), With<Creature>>,
+    pickable_q: Query<&Parent, With<Pickable>>,
+    mut item_q: Query<(&Item, Option<&mut Quantity>)>,
     mut ev_pickup_item: EventReader<PickUpItemEvent>,
+    mut ev_store_items: EventWriter<StoreItemsEvent>,
+    mut ev_inventory_full: EventWriter<InventoryFullEvent>,
 ) {
+    let mut planned: HashMap<Entity, (Vec<InventoryStack>, usize)> = HashMap::new();
+
     for ev in ev_pickup_item.iter() {
         println!("PickUpItemEvent {:?} - {:?}", ev.0, ev.1);
 
-        let mut inventory = match inventory_q.get_mut(ev.0) {
-            Ok(i) => i,
+        let (inventory, capacity) = match creature_q.get(ev.0) {
+            Ok(creature) => creature,
             Err(_) => continue,
         };
 
         println!("Inventory found {:?}", inventory.0.len());
 
-        // Remove DroppedItem children bundle
+        // The pickable sensor is a child of the dropped item
+        let item_entity = match pickable_q.get(ev.1) {
+            Ok(parent) => parent.get(),
+            Err(_) => continue,
+        };
 
-        // inventory.0.push(ev.1)
-    }
-}
+        let (item_type, quantity) = match item_q.get(item_entity) {
+            Ok((item, quantity)) => stack_of(item, quantity),
+            Err(_) => continue,
+        };
 
-pub fn drop_item_system() {
-    // read drop item events
-    // get item from entity param
-    // add mesh
-    // add sensor
-    // add collider
+        let (stacks, used_slots) = planned.entry(ev.0).or_insert_with(|| {
+            let stacks = inventory.stacks(|entity| {
+                item_q
+                    .get(entity)
+                    .ok()
+                    .map(|(item, quantity)| stack_of(item, quantity))
+            });
+            (stacks, inventory.0.len())
+        });
+        let plan = capacity.plan_store(stacks, *used_slots, item_type, quantity);
+        *used_slots += plan.apply(stacks, item_type);
+        let picked = quantity - plan.refused;
+
+        if plan.refused > 0 {
+            println!(
+                "Inventory full, {}x {} left",
+                plan.refused,
+                item_type.name()
+            );
+            ev_inventory_full.send(InventoryFullEvent(ev.0, item_type, plan.refused));
+        }
+
+        if picked == 0 {
+            continue;
+        }
+
+        ev_store_items.send(StoreItemsEvent(ev.0, item_type, picked));
+
+        // Remove DroppedItem, or only the picked quantity
+        if plan.refused == 0 {
+            commands.entity(item_entity).despawn_recursive();
+        } else if let Ok((_, Some(mut quantity))) = item_q.get_mut(item_entity) {
+            quantity.0 = plan.refused;
+        }
+    }
 }
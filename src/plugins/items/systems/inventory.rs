@@ -0,0 +1,284 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::plugins::{
+    creature::creature_plugin::Creature,
+    items::{
+        items_plugin::{
+            Inventory, InventoryCapacity, InventoryFullEvent, Item, ItemType, MergeStacksEvent,
+            Quantity, SplitStackEvent, StoreItemsEvent,
+        },
+        systems::{create_items::spawn_item, dropped_items::insert_dropped_item_bundle},
+    },
+};
+
+/// An inventory slot with its item type and quantity
+pub struct InventoryStack {
+    pub entity: Entity,
+    pub item_type: ItemType,
+    pub quantity: u32,
+}
+
+/// Where the items of a StoreItemsEvent will go
+pub struct StorePlan {
+    /// Quantities added to existing stacks, by index in the planned stacks
+    pub merges: Vec<(usize, u32)>,
+    /// Quantities of the new stacks to create
+    pub new_stacks: Vec<u32>,
+    /// Quantity which does not fit in the inventory
+    pub refused: u32,
+}
+
+impl Inventory {
+    /// List the stacks of the inventory from their item type and quantity
+    pub fn stacks(
+        &self,
+        stack_of: impl Fn(Entity) -> Option<(ItemType, u32)>,
+    ) -> Vec<InventoryStack> {
+        self.0
+            .iter()
+            .filter_map(|entity| {
+                stack_of(*entity).map(|(item_type, quantity)| InventoryStack {
+                    entity: *entity,
+                    item_type,
+                    quantity,
+                })
+            })
+            .collect()
+    }
+}
+
+impl StorePlan {
+    /// Apply the plan to the stacks it was planned on, returns the number of slots used
+    ///
+    /// The new stacks are not spawned yet, they get a placeholder entity as the plans
+    /// address the stacks by index
+    pub fn apply(&self, stacks: &mut Vec<InventoryStack>, item_type: ItemType) -> usize {
+        for (index, added) in self.merges.iter() {
+            stacks[*index].quantity += added;
+        }
+
+        for quantity in self.new_stacks.iter() {
+            stacks.push(InventoryStack {
+                entity: Entity::from_raw(u32::MAX),
+                item_type,
+                quantity: *quantity,
+            });
+        }
+
+        self.new_stacks.len()
+    }
+}
+
+/// Item type and quantity of a stack, items without Quantity count as a single item
+pub fn stack_of(item: &Item, quantity: Option<&Quantity>) -> (ItemType, u32) {
+    (item.item_type, quantity.map_or(1, |quantity| quantity.0))
+}
+
+/// Total weight of the inventory stacks
+pub fn stacks_weight(stacks: &[InventoryStack]) -> f32 {
+    stacks
+        .iter()
+        .map(|stack| stack.item_type.weight() * stack.quantity as f32)
+        .sum()
+}
+
+impl InventoryCapacity {
+    /// Split a quantity of items between the existing stacks and new slots,
+    /// within the slots and weight limits of the inventory
+    pub fn plan_store(
+        &self,
+        stacks: &[InventoryStack],
+        used_slots: usize,
+        item_type: ItemType,
+        quantity: u32,
+    ) -> StorePlan {
+        let free_weight = (self.max_weight - stacks_weight(stacks)).max(0.);
+        let storable = quantity.min((free_weight / item_type.weight()).floor() as u32);
+
+        let max_stack = item_type.max_stack();
+        let mut remaining = storable;
+
+        let mut merges = Vec::new();
+        for (index, stack) in stacks
+            .iter()
+            .enumerate()
+            .filter(|(_, stack)| stack.item_type == item_type && stack.quantity < max_stack)
+        {
+            if remaining == 0 {
+                break;
+            }
+
+            let added = remaining.min(max_stack - stack.quantity);
+            merges.push((index, added));
+            remaining -= added;
+        }
+
+        let mut new_stacks = Vec::new();
+        let mut slots = used_slots;
+        while remaining > 0 && slots < self.slots {
+            let added = remaining.min(max_stack);
+            new_stacks.push(added);
+            remaining -= added;
+            slots += 1;
+        }
+
+        StorePlan {
+            merges,
+            new_stacks,
+            refused: quantity - storable + remaining,
+        }
+    }
+}
+
+/// # store_items_system
+/// Add items to the creature inventory, filling existing stacks before using new slots
+///
+/// Items which do not fit are dropped on the floor and an InventoryFullEvent is sent
+///
+/// Stacks spawned by this run are not visible to the item query until the commands are applied,
+/// so they are tracked locally and topped up by the next events of the same frame
+///
+pub fn store_items_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut creature_q: Query<(&Transform, &mut Inventory, &InventoryCapacity), With<Creature>>,
+    mut item_q: Query<(&Item, Option<&mut Quantity>)>,
+    mut ev_store_items: EventReader<StoreItemsEvent>,
+    mut ev_inventory_full: EventWriter<InventoryFullEvent>,
+) {
+    let mut spawned_stacks: HashMap<Entity, (ItemType, u32)> = HashMap::new();
+
+    for ev in ev_store_items.iter() {
+        let (transform, mut inventory, capacity) = match creature_q.get_mut(ev.0) {
+            Ok(creature) => creature,
+            Err(_) => continue,
+        };
+
+        let stacks = inventory.stacks(|entity| {
+            item_q
+                .get(entity)
+                .ok()
+                .map(|(item, quantity)| stack_of(item, quantity))
+                .or_else(|| spawned_stacks.get(&entity).copied())
+        });
+        let plan = capacity.plan_store(&stacks, inventory.0.len(), ev.1, ev.2);
+
+        for (index, added) in plan.merges {
+            let entity = stacks[index].entity;
+            if let Some((_, quantity)) = spawned_stacks.get_mut(&entity) {
+                *quantity += added;
+                commands.entity(entity).insert(Quantity(*quantity));
+            } else if let Ok((_, Some(mut quantity))) = item_q.get_mut(entity) {
+                quantity.0 += added;
+            }
+        }
+
+        for quantity in plan.new_stacks {
+            let item = spawn_item(&mut commands, ev.1, quantity);
+            spawned_stacks.insert(item, (ev.1, quantity));
+            inventory.0.push(item);
+        }
+
+        if plan.refused > 0 {
+            println!("Inventory full, dropping {}x {}", plan.refused, ev.1.name());
+            ev_inventory_full.send(InventoryFullEvent(ev.0, ev.1, plan.refused));
+
+            let item = spawn_item(&mut commands, ev.1, plan.refused);
+            insert_dropped_item_bundle(
+                &mut commands.entity(item),
+                &mut meshes,
+                &mut materials,
+                ev.1,
+                Transform::from_translation(transform.translation),
+            );
+        }
+    }
+}
+
+/**
+ * Move as many items as possible from a stack into another stack of the same type
+ */
+pub fn merge_stacks_system(
+    mut commands: Commands,
+    mut creature_q: Query<&mut Inventory, With<Creature>>,
+    mut item_q: Query<(&Item, &mut Quantity)>,
+    mut ev_merge_stacks: EventReader<MergeStacksEvent>,
+) {
+    for ev in ev_merge_stacks.iter() {
+        let mut inventory = match creature_q.get_mut(ev.0) {
+            Ok(inventory) => inventory,
+            Err(_) => continue,
+        };
+
+        if ev.1 == ev.2 || !inventory.0.contains(&ev.1) || !inventory.0.contains(&ev.2) {
+            continue;
+        }
+
+        let (from_type, from_quantity) = match item_q.get(ev.1) {
+            Ok((item, quantity)) => (item.item_type, quantity.0),
+            Err(_) => continue,
+        };
+
+        let (into_type, into_quantity) = match item_q.get(ev.2) {
+            Ok((item, quantity)) => (item.item_type, quantity.0),
+            Err(_) => continue,
+        };
+
+        if from_type != into_type {
+            continue;
+        }
+
+        let moved = from_quantity.min(into_type.max_stack().saturating_sub(into_quantity));
+        if moved == 0 {
+            continue;
+        }
+
+        if let Ok((_, mut quantity)) = item_q.get_mut(ev.2) {
+            quantity.0 += moved;
+        }
+
+        if moved == from_quantity {
+            inventory.0.retain(|item| *item != ev.1);
+            commands.entity(ev.1).despawn_recursive();
+        } else if let Ok((_, mut quantity)) = item_q.get_mut(ev.1) {
+            quantity.0 -= moved;
+        }
+    }
+}
+
+/**
+ * Move part of a stack to a new inventory slot
+ */
+pub fn split_stack_system(
+    mut commands: Commands,
+    mut creature_q: Query<(&mut Inventory, &InventoryCapacity), With<Creature>>,
+    mut item_q: Query<(&Item, &mut Quantity)>,
+    mut ev_split_stack: EventReader<SplitStackEvent>,
+) {
+    for ev in ev_split_stack.iter() {
+        let (mut inventory, capacity) = match creature_q.get_mut(ev.0) {
+            Ok(creature) => creature,
+            Err(_) => continue,
+        };
+
+        if !inventory.0.contains(&ev.1) || inventory.0.len() >= capacity.slots {
+            continue;
+        }
+
+        let (item, mut quantity) = match item_q.get_mut(ev.1) {
+            Ok(stack) => stack,
+            Err(_) => continue,
+        };
+
+        if ev.2 == 0 || ev.2 >= quantity.0 {
+            continue;
+        }
+
+        quantity.0 -= ev.2;
+
+        let new_stack = spawn_item(&mut commands, item.item_type, ev.2);
+        inventory.0.push(new_stack);
+    }
+}
@@ -1,5 +1,6 @@
 pub mod create_items;
 pub mod dropped_items;
 pub mod equip_item;
+pub mod inventory;
 pub mod update_items;
 pub mod use_item;
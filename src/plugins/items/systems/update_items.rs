@@ -1,6 +1,12 @@
 use bevy::prelude::*;
 
-use crate::plugins::items::items_plugin::{AnimateVisualItem, AnimationTimer};
+use crate::{
+    plugins::items::items_plugin::{
+        ActivateItemEvent, AnimateVisualItem, AnimationTimer, Belt, BufferedActivation,
+        ChargingAttack, Combo, Inventory, Item, Swing,
+    },
+    BELT_SIZE, HEAVY_WINDUP_ARC,
+};
 
 /**
  * Query all equiped items and call their update function with the current delta time
@@ -14,26 +20,129 @@ pub fn start_items_animation_system(
     }
 }
 
+/**
+ * Rotate the swinging items toward the end of the arc of their attack
+ */
 pub fn animate_items_system(
     time: Res<Time>,
     mut commands: Commands,
     mut animating_items_q: Query<
-        (Entity, &mut Transform, &mut AnimationTimer),
+        (
+            Entity,
+            &Item,
+            Option<&Swing>,
+            &mut Transform,
+            &mut AnimationTimer,
+        ),
         With<AnimateVisualItem>,
     >,
 ) {
-    for (entity, mut transform, mut timer) in animating_items_q.iter_mut() {
+    for (entity, item, swing, mut transform, mut timer) in animating_items_q.iter_mut() {
         timer.0.tick(time.delta());
 
         if timer.0.finished() {
             println!("Animation finished!");
-            commands.entity(entity).remove::<AnimateVisualItem>();
+            commands
+                .entity(entity)
+                .remove::<AnimateVisualItem>()
+                .remove::<Swing>();
             transform.rotation = Quat::default();
             timer.0.reset();
         } else {
-            transform.rotation = transform
-                .rotation
-                .slerp(Quat::from_rotation_x(2.), timer.0.percent());
+            let arc = match swing {
+                Some(swing) => item.item_type.attack(swing.attack).arc,
+                None => Quat::from_rotation_x(2.),
+            };
+
+            transform.rotation = transform.rotation.slerp(arc, timer.0.percent());
+        }
+    }
+}
+
+/**
+ * Pull the charging items back as the charge grows
+ */
+pub fn charge_animation_system(
+    time: Res<Time>,
+    mut charging_q: Query<(&mut ChargingAttack, &mut Transform), Without<AnimateVisualItem>>,
+) {
+    for (mut charge, mut transform) in charging_q.iter_mut() {
+        charge.0.tick(time.delta());
+        transform.rotation = Quat::from_rotation_x(-HEAVY_WINDUP_ARC * charge.0.percent());
+    }
+}
+
+/**
+ * Close the combo window of the items once their swing is over, the next swing restarts the combo
+ */
+pub fn combo_window_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut combo_q: Query<(Entity, &mut Combo), Without<AnimateVisualItem>>,
+) {
+    for (entity, mut combo) in combo_q.iter_mut() {
+        if combo.window.tick(time.delta()).finished() {
+            commands.entity(entity).remove::<Combo>();
+        }
+    }
+}
+
+pub fn tick_items_cooldown_system(time: Res<Time>, mut items_q: Query<&mut Item>) {
+    for mut item in items_q.iter_mut() {
+        item.cooldown_timer.0.tick(time.delta());
+    }
+}
+
+/**
+ * Replay the buffered activations once the item is ready and its animation is over,
+ * forget them when the buffer runs out
+ */
+pub fn buffered_activation_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut buffered_q: Query<
+        (Entity, &Item, &Parent, &mut BufferedActivation),
+        Without<AnimateVisualItem>,
+    >,
+    mut ev_activate_item: EventWriter<ActivateItemEvent>,
+) {
+    for (entity, item, parent, mut buffer) in buffered_q.iter_mut() {
+        if item.is_ready() {
+            commands.entity(entity).remove::<BufferedActivation>();
+            ev_activate_item.send(ActivateItemEvent(parent.get(), entity));
+            continue;
+        }
+
+        if buffer.0.tick(time.delta()).finished() {
+            commands.entity(entity).remove::<BufferedActivation>();
+        }
+    }
+}
+
+/**
+ * Keep the belt in sync with the inventory: drop the items which left the inventory
+ * and fill the free slots with the consumables of the inventory
+ */
+pub fn update_belt_system(
+    mut belt_q: Query<(&Inventory, &mut Belt), Changed<Inventory>>,
+    items_q: Query<&Item>,
+) {
+    for (inventory, mut belt) in belt_q.iter_mut() {
+        belt.0.retain(|item| inventory.0.contains(item));
+
+        for item_entity in inventory.0.iter() {
+            if belt.0.len() >= BELT_SIZE {
+                break;
+            }
+
+            let is_consumable = match items_q.get(*item_entity) {
+                Ok(item) => item.item_type.is_consumable(),
+                Err(_) => false,
+            };
+
+            if is_consumable && !belt.0.contains(item_entity) {
+                belt.0.push(*item_entity);
+            }
         }
     }
 }
@@ -1,6 +1,269 @@
+use bevy::prelude::*;
+
+use crate::{
+    plugins::{
+        combat::combat_events::ExplosionEvent,
+        creature::{
+            creature_plugin::Creature,
+            systems::{
+                stamina::Stamina,
+                stats::{Staggered, Stats, StatsBuff},
+                status_effects::StatusEffects,
+            },
+        },
+        items::items_plugin::{
+            ActivateItemEvent, AnimateVisualItem, AttackKind, Belt, BufferedActivation,
+            ChargeItemEvent, Charges, ChargingAttack, Combo, Inventory, Item, ItemEffect, Quantity,
+            ReleaseItemEvent, Swing,
+        },
+    },
+    COMBO_WINDOW, HEAVY_CHARGE_TIME, INPUT_BUFFER_TIME,
+};
+
+/// # activate_item_system
+/// Use the item received with the ActivateItemEvent if its cooldown is over
+///
+/// Equipped weapons and tools start a swing, an activation received during the end
+/// of the previous swing is buffered and replayed when the item is ready,
+/// an activation coming shortly after the previous swing chains the next step of the combo,
+/// staggered and exhausted creatures cannot swing, each swing spends stamina
+///
+/// Consumables apply their effect to the creature and lose a charge,
+/// an empty item is taken from its stack and the stack is removed once empty
+///
+pub fn activate_item_system(
+    mut commands: Commands,
+    mut creature_q: Query<
+        (
+            &Transform,
+            &mut Stats,
+            &mut StatusEffects,
+            &mut Stamina,
+            &mut Inventory,
+            &mut Belt,
+            Option<&mut StatsBuff>,
+            Option<&Staggered>,
+        ),
+        With<Creature>,
+    >,
+    mut item_q: Query<(
+        &mut Item,
+        Option<&mut Charges>,
+        Option<&mut Quantity>,
+        Option<&AnimateVisualItem>,
+        Option<&Combo>,
+    )>,
+    mut ev_activate_item: EventReader<ActivateItemEvent>,
+    mut ev_explosion: EventWriter<ExplosionEvent>,
+) {
+    for ev in ev_activate_item.iter() {
+        let (
+            transform,
+            mut stats,
+            mut status_effects,
+            mut stamina,
+            mut inventory,
+            mut belt,
+            buff,
+            staggered,
+        ) = match creature_q.get_mut(ev.0) {
+            Ok(creature) => creature,
+            Err(_) => continue,
+        };
+
+        let (mut item, charges, quantity, animating, combo) = match item_q.get_mut(ev.1) {
+            Ok(item) => item,
+            Err(_) => continue,
+        };
+
+        // Ranged items are drawn and released with the right click
+        if item.item_type.is_ranged() {
+            continue;
+        }
+
+        if item.item_type.is_equipable() {
+            // Staggered creatures cannot attack
+            if staggered.is_some() {
+                continue;
+            }
+
+            if !item.is_ready() || animating.is_some() {
+                // Queue the activation when the item is almost ready, earlier ones are ignored
+                if item.remaining_cooldown() <= INPUT_BUFFER_TIME {
+                    commands
+                        .entity(ev.1)
+                        .insert(BufferedActivation(Timer::from_seconds(
+                            INPUT_BUFFER_TIME,
+                            false,
+                        )));
+                }
+                continue;
+            }
+
+            let step = match combo {
+                Some(combo) => (combo.step + 1) % item.item_type.combo_length(),
+                None => 0,
+            };
+            let attack = item.item_type.attack(AttackKind::Combo(step));
+
+            if !stamina.try_consume(item.item_type.stamina_cost() * attack.stamina) {
+                println!("Too exhausted to use {}", item.item_type.name());
+                continue;
+            }
+
+            item.activate();
+            commands
+                .entity(ev.1)
+                .insert(AnimateVisualItem)
+                .insert(Swing::new(AttackKind::Combo(step)))
+                .insert(Combo {
+                    step,
+                    window: Timer::from_seconds(COMBO_WINDOW, false),
+                });
+            continue;
+        }
+
+        if !item.is_ready() {
+            println!("{} is on cooldown", item.item_type.name());
+            continue;
+        }
+
+        let effect = match item.item_type.consumable_effect() {
+            Some(effect) => effect,
+            None => continue,
+        };
+
+        item.activate();
+        println!("Use {}", item.item_type.name());
+
+        match effect {
+            ItemEffect::Heal(amount) => stats.heal(amount),
+            ItemEffect::Buff { atk, duration } => match buff {
+                // Eating again only refreshes the current buff
+                Some(mut buff) => buff.timer.reset(),
+                None => {
+                    stats.atk += atk;
+                    commands.entity(ev.0).insert(StatsBuff {
+                        atk,
+                        timer: Timer::from_seconds(duration, false),
+                    });
+                }
+            },
+            ItemEffect::Explode { damage, radius } => {
+                ev_explosion.send(ExplosionEvent(transform.translation, radius, damage));
+            }
+            ItemEffect::Status(effect) => {
+                status_effects.apply(effect);
+            }
+            ItemEffect::Cure { effect, immunity } => status_effects.cure(effect, immunity),
+        }
+
+        // Consume a charge, take the next item of the stack when there is none left
+        let empty = match charges {
+            Some(mut charges) => {
+                charges.0 = charges.0.saturating_sub(1);
+
+                if charges.0 > 0 {
+                    false
+                } else {
+                    match quantity {
+                        Some(mut quantity) if quantity.0 > 1 => {
+                            quantity.0 -= 1;
+                            charges.0 = item.item_type.max_charges().unwrap_or(1);
+                            false
+                        }
+                        _ => true,
+                    }
+                }
+            }
+            None => true,
+        };
+
+        if empty {
+            inventory.0.retain(|item| *item != ev.1);
+            belt.0.retain(|item| *item != ev.1);
+            commands.entity(ev.1).despawn_recursive();
+        }
+    }
+}
+
+/**
+ * Start charging the heavy attack of the item when it is ready,
+ * items without heavy attack and busy items are activated right away
+ */
+pub fn charge_item_system(
+    mut commands: Commands,
+    creature_q: Query<(), (With<Creature>, Without<Staggered>)>,
+    item_q: Query<(&Item, Option<&AnimateVisualItem>)>,
+    mut ev_charge_item: EventReader<ChargeItemEvent>,
+    mut ev_activate_item: EventWriter<ActivateItemEvent>,
+) {
+    for ev in ev_charge_item.iter() {
+        let (item, animating) = match item_q.get(ev.1) {
+            Ok(item) => item,
+            Err(_) => continue,
+        };
+
+        if !item.item_type.has_heavy_attack()
+            || !item.is_ready()
+            || animating.is_some()
+            || !creature_q.contains(ev.0)
+        {
+            ev_activate_item.send(ActivateItemEvent(ev.0, ev.1));
+            continue;
+        }
+
+        commands
+            .entity(ev.1)
+            .insert(ChargingAttack(Timer::from_seconds(
+                HEAVY_CHARGE_TIME,
+                false,
+            )));
+    }
+}
+
 /**
- * Get the Equiped item bundle and activate it when event is received
+ * Release the charging item: a full charge swings the heavy attack and breaks the combo,
+ * a partial one plays the next light swing
  */
-pub fn activate_equiped_item(// ItemBundle with EquippedItem
+pub fn release_item_system(
+    mut commands: Commands,
+    mut creature_q: Query<(&mut Stamina, Option<&Staggered>), With<Creature>>,
+    mut item_q: Query<(&mut Item, &ChargingAttack, &mut Transform)>,
+    mut ev_release_item: EventReader<ReleaseItemEvent>,
+    mut ev_activate_item: EventWriter<ActivateItemEvent>,
 ) {
+    for ev in ev_release_item.iter() {
+        let (mut item, charge, mut transform) = match item_q.get_mut(ev.1) {
+            Ok(item) => item,
+            Err(_) => continue,
+        };
+
+        commands.entity(ev.1).remove::<ChargingAttack>();
+        transform.rotation = Quat::default();
+
+        if !charge.0.finished() {
+            ev_activate_item.send(ActivateItemEvent(ev.0, ev.1));
+            continue;
+        }
+
+        // The charge is lost when the creature is staggered while charging
+        let mut stamina = match creature_q.get_mut(ev.0) {
+            Ok((stamina, None)) => stamina,
+            _ => continue,
+        };
+
+        let attack = item.item_type.attack(AttackKind::Heavy);
+        if !stamina.try_consume(item.item_type.stamina_cost() * attack.stamina) {
+            println!("Too exhausted to use {}", item.item_type.name());
+            continue;
+        }
+
+        item.activate();
+        commands
+            .entity(ev.1)
+            .remove::<Combo>()
+            .insert(AnimateVisualItem)
+            .insert(Swing::new(AttackKind::Heavy));
+    }
 }
@@ -1,11 +1,25 @@
 use bevy::prelude::*;
 use bevy_rapier3d::prelude::Velocity;
 
-use crate::{utils::error::ErrorMessage, GameState};
+use crate::{
+    plugins::world::{
+        generation::WorldGenerator,
+        systems::safe_zones::keep_out_of_safe_zones,
+        weather::RegionalWeather,
+        world_plugin::{Dormant, SafeZone},
+    },
+    utils::error::ErrorMessage,
+    GameState, EXHAUSTED_SPEED_FACTOR,
+};
 
 use super::creature::{
     creature_plugin::{CreatureType, Monster, Player},
-    systems::stats::{BrainState, ConsciousnessStateEnum},
+    systems::{
+        ground::{climbable_direction, GroundContact},
+        stamina::Stamina,
+        stats::{BrainState, ConsciousnessStateEnum, Staggered},
+        status_effects::StatusEffects,
+    },
 };
 
 #[derive(Default, Component, Debug)]
@@ -35,6 +49,8 @@ impl Plugin for LocationPlugin {
 // Move non player creatures
 fn location_system(
     time: Res<Time>,
+    generator: Res<WorldGenerator>,
+    regional_weather: Res<RegionalWeather>,
     mut creatures_query: Query<
         (
             &Transform,
@@ -42,38 +58,71 @@ fn location_system(
             &mut Location,
             &BrainState,
             &CreatureType,
+            &StatusEffects,
+            &Stamina,
+            &GroundContact,
+            Option<&Staggered>,
         ),
-        (With<Monster>, Without<Player>),
+        (With<Monster>, Without<Player>, Without<Dormant>),
     >,
+    safe_zone_q: Query<(&GlobalTransform, &SafeZone)>,
 ) {
     let mut closure = || {
-        for (transform, mut velocity, mut location, brain_state, creature_type) in
-            creatures_query.iter_mut()
+        for (
+            transform,
+            mut velocity,
+            mut location,
+            brain_state,
+            creature_type,
+            status_effects,
+            stamina,
+            ground,
+            staggered,
+        ) in creatures_query.iter_mut()
         {
+            let mut speed = creature_type.speed()
+                * status_effects.speed_multiplier()
+                * regional_weather
+                    .at(transform.translation)
+                    .speed_multiplier();
+            if stamina.exhausted {
+                speed *= EXHAUSTED_SPEED_FACTOR;
+            }
+
             // Get entity position
             // Update location from parent translation
             let creature_position = transform.translation;
             location.position = Some(creature_position);
 
+            // Keep the knockback velocity until the end of the stagger
+            if staggered.is_some() {
+                continue;
+            }
+
             if let Some(destination) = location.destination {
+                // Heights differ along the terrain, only the horizontal distance matters
+                let offset = (destination - creature_position) * Vec3::new(1., 0., 1.);
+
                 // if transform.translation is close enough to destination, remove destination
-                if destination.abs_diff_eq(
-                    creature_position,
-                    creature_type.speed() * time.delta_seconds(),
-                ) {
+                if offset.length() <= speed * time.delta_seconds() {
                     location.destination = None;
-                    velocity.linvel = Vec3::new(0., 0., 0.);
-                    return Ok(());
-                }
-
-                // Get normalized vector to destination
-                let direction = (destination - creature_position).normalize();
-                if brain_state.conscious == ConsciousnessStateEnum::Awake {
-                    velocity.linvel = direction * creature_type.speed();
+                    velocity.linvel = Vec3::new(0., velocity.linvel.y, 0.);
+                } else {
+                    // Walk around the slopes too steep to climb
+                    let direction =
+                        climbable_direction(&generator, creature_position, offset.normalize())
+                            .unwrap_or(Vec3::ZERO);
+                    if brain_state.conscious == ConsciousnessStateEnum::Awake {
+                        velocity.linvel = ground.walk_velocity(direction * speed, velocity.linvel);
+                    }
                 }
             } else {
-                velocity.linvel = Vec3::new(0., 0., 0.);
+                velocity.linvel = Vec3::new(0., velocity.linvel.y, 0.);
             }
+
+            // Monsters cannot enter the safe zones
+            velocity.linvel =
+                keep_out_of_safe_zones(safe_zone_q.iter(), creature_position, velocity.linvel);
         }
 
         Ok::<(), ErrorMessage>(())
@@ -1,9 +1,13 @@
 pub mod camera;
 pub mod combat;
+pub mod crafting;
 pub mod creature;
+pub mod exploration;
+pub mod farming;
+pub mod interaction;
 // pub mod hud;
 pub mod items;
 pub mod location;
 pub mod player;
 pub mod ui;
-// pub mod world;
+pub mod world;
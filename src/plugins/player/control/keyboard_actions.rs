@@ -4,18 +4,184 @@ use bevy::prelude::*;
 use bevy_rapier3d::prelude::*;
 
 use crate::plugins::{
+    combat::combat_events::{LockTargetEvent, ReleaseTargetEvent},
+    crafting::crafting_events::ToggleCraftingMenuEvent,
     creature::creature_plugin::Player,
-    items::items_plugin::{EquipItemEvent, Inventory, Item, ItemType, PickUpItemEvent, Pickable},
+    interaction::{
+        interactables::{nearest_interactable, Interactable},
+        interaction_events::InteractEvent,
+    },
+    items::{
+        items_plugin::{
+            ActivateItemEvent, Belt, EquipItemEvent, Inventory, Item, ItemType, MergeStacksEvent,
+            PickUpItemEvent, Pickable, Quantity, SplitStackEvent, UnequipItemEvent,
+        },
+        systems::inventory::stack_of,
+    },
 };
 
-pub fn equip_item_key(
+const BELT_KEYS: [KeyCode; 4] = [KeyCode::Key1, KeyCode::Key2, KeyCode::Key3, KeyCode::Key4];
+
+/// Number keys of the first inventory slots, 0 is the tenth slot
+const INVENTORY_SLOT_KEYS: [KeyCode; 10] = [
+    KeyCode::Key1,
+    KeyCode::Key2,
+    KeyCode::Key3,
+    KeyCode::Key4,
+    KeyCode::Key5,
+    KeyCode::Key6,
+    KeyCode::Key7,
+    KeyCode::Key8,
+    KeyCode::Key9,
+    KeyCode::Key0,
+];
+
+/// Alt splits the inventory stacks, control merges them
+fn split_modifier(keyboard_input: &Input<KeyCode>) -> bool {
+    keyboard_input.pressed(KeyCode::LAlt) || keyboard_input.pressed(KeyCode::RAlt)
+}
+
+fn merge_modifier(keyboard_input: &Input<KeyCode>) -> bool {
+    keyboard_input.pressed(KeyCode::LControl) || keyboard_input.pressed(KeyCode::RControl)
+}
+
+/**
+ * Interact with the nearest interactable in range with E, switch to the next equipable item
+ * otherwise. R puts the equipped item back in the inventory
+ */
+pub fn interact_key(
     keyboard_input: Res<Input<KeyCode>>,
-    player_q: Query<Entity, With<Player>>,
+    player_q: Query<(Entity, &Transform), With<Player>>,
+    interactable_q: Query<(Entity, &GlobalTransform), With<Interactable>>,
     mut ev_equip_item: EventWriter<EquipItemEvent>,
+    mut ev_unequip_item: EventWriter<UnequipItemEvent>,
+    mut ev_interact: EventWriter<InteractEvent>,
+) {
+    let (player, transform) = match player_q.get_single() {
+        Ok(player) => player,
+        Err(_) => return,
+    };
+
+    if keyboard_input.just_pressed(KeyCode::E) {
+        match nearest_interactable(interactable_q.iter(), transform.translation) {
+            Some(target) => ev_interact.send(InteractEvent(player, target)),
+            None => ev_equip_item.send(EquipItemEvent(player)),
+        }
+    }
+
+    if keyboard_input.just_pressed(KeyCode::R) {
+        ev_unequip_item.send(UnequipItemEvent(player));
+    }
+}
+
+pub fn crafting_menu_key(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut ev_toggle_crafting_menu: EventWriter<ToggleCraftingMenuEvent>,
 ) {
-    if keyboard_input.pressed(KeyCode::E) {
-        let player = player_q.get_single().expect("No Player found");
+    if keyboard_input.just_pressed(KeyCode::C) {
+        ev_toggle_crafting_menu.send(ToggleCraftingMenuEvent);
+    }
+}
+
+/**
+ * Lock the nearest monster with tab, pressing it again cycles between the monsters in range,
+ * release the target with X
+ */
+pub fn target_keys(
+    keyboard_input: Res<Input<KeyCode>>,
+    player_q: Query<Entity, With<Player>>,
+    mut ev_lock_target: EventWriter<LockTargetEvent>,
+    mut ev_release_target: EventWriter<ReleaseTargetEvent>,
+) {
+    let player = match player_q.get_single() {
+        Ok(player) => player,
+        Err(_) => return,
+    };
+
+    if keyboard_input.just_pressed(KeyCode::Tab) {
+        ev_lock_target.send(LockTargetEvent(player, None));
+    }
+
+    if keyboard_input.just_pressed(KeyCode::X) {
+        ev_release_target.send(ReleaseTargetEvent(player));
+    }
+}
+
+/**
+ * Use the belt items with the number keys
+ */
+pub fn belt_keys(
+    keyboard_input: Res<Input<KeyCode>>,
+    player_q: Query<(Entity, &Belt), With<Player>>,
+    mut ev_activate_item: EventWriter<ActivateItemEvent>,
+) {
+    if split_modifier(&keyboard_input) || merge_modifier(&keyboard_input) {
+        return;
+    }
+
+    let (player, belt) = match player_q.get_single() {
+        Ok(player) => player,
+        Err(_) => return,
+    };
+
+    for (slot, key) in BELT_KEYS.iter().enumerate() {
+        if !keyboard_input.just_pressed(*key) {
+            continue;
+        }
+
+        if let Some(item) = belt.0.get(slot) {
+            ev_activate_item.send(ActivateItemEvent(player, *item));
+        }
+    }
+}
+
+/**
+ * Manage the inventory stacks with the number key of their slot: alt splits the stack in two,
+ * control merges the other stacks of the same type into it
+ */
+pub fn stack_keys(
+    keyboard_input: Res<Input<KeyCode>>,
+    player_q: Query<(Entity, &Inventory), With<Player>>,
+    item_q: Query<(&Item, Option<&Quantity>)>,
+    mut ev_merge_stacks: EventWriter<MergeStacksEvent>,
+    mut ev_split_stack: EventWriter<SplitStackEvent>,
+) {
+    let split = split_modifier(&keyboard_input);
+    let merge = merge_modifier(&keyboard_input);
+    if !split && !merge {
+        return;
+    }
+
+    let (player, inventory) = match player_q.get_single() {
+        Ok(player) => player,
+        Err(_) => return,
+    };
+
+    for (slot, key) in INVENTORY_SLOT_KEYS.iter().enumerate() {
+        if !keyboard_input.just_pressed(*key) {
+            continue;
+        }
+
+        let stack = match inventory.0.get(slot) {
+            Some(stack) => *stack,
+            None => continue,
+        };
+
+        let (item_type, quantity) = match item_q.get(stack) {
+            Ok((item, quantity)) => stack_of(item, quantity),
+            Err(_) => continue,
+        };
 
-        ev_equip_item.send(EquipItemEvent(player));
+        if split {
+            ev_split_stack.send(SplitStackEvent(player, stack, quantity / 2));
+        } else {
+            for other in inventory.0.iter().filter(|other| **other != stack) {
+                if let Ok((item, _)) = item_q.get(*other) {
+                    if item.item_type == item_type {
+                        ev_merge_stacks.send(MergeStacksEvent(player, *other, stack));
+                    }
+                }
+            }
+        }
     }
 }
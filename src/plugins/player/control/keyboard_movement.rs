@@ -1,37 +1,92 @@
-use bevy::prelude::{Input, KeyCode, Query, Res, Vec3, With};
+use bevy::prelude::{
+    Entity, EventWriter, Input, KeyCode, Query, Res, Time, Transform, Vec3, With, Without,
+};
 use bevy_rapier3d::prelude::Velocity;
 
 use crate::{
-    plugins::creature::creature_plugin::Player, utils::error::ErrorMessage, HUMAN_STEP_DISTANCE,
+    plugins::{
+        combat::{
+            combat_events::{BlockEvent, DodgeEvent},
+            dodge::Dodging,
+        },
+        creature::{
+            creature_plugin::Player,
+            systems::{
+                ground::GroundContact,
+                stamina::Stamina,
+                stats::{BrainState, ConsciousnessStateEnum, Staggered},
+                status_effects::StatusEffects,
+            },
+        },
+        world::weather::RegionalWeather,
+    },
+    utils::error::ErrorMessage,
+    EXHAUSTED_SPEED_FACTOR, HUMAN_STEP_DISTANCE, JUMP_SPEED, JUMP_STAMINA_COST,
+    SPRINT_SPEED_FACTOR, SPRINT_STAMINA_COST,
 };
 
 pub fn wasd_movement(
+    time: Res<Time>,
     keyboard_input: Res<Input<KeyCode>>,
-    mut q_parent: Query<&mut Velocity, With<Player>>,
+    regional_weather: Res<RegionalWeather>,
+    mut q_parent: Query<
+        (
+            &Transform,
+            &mut Velocity,
+            &StatusEffects,
+            &mut Stamina,
+            &GroundContact,
+            &BrainState,
+            Option<&Staggered>,
+            Option<&Dodging>,
+        ),
+        With<Player>,
+    >,
 ) {
     let mut closure = || {
-        let mut velocity = q_parent.get_single_mut()?;
-
-        let mut velocity_vector = Vec3::splat(0.);
-
-        if keyboard_input.pressed(KeyCode::Left) || keyboard_input.pressed(KeyCode::A) {
-            velocity_vector.x = -1.;
-        }
+        let (
+            transform,
+            mut velocity,
+            status_effects,
+            mut stamina,
+            ground,
+            brain_state,
+            staggered,
+            dodging,
+        ) = q_parent.get_single_mut()?;
 
-        if keyboard_input.pressed(KeyCode::Right) || keyboard_input.pressed(KeyCode::D) {
-            velocity_vector.x = 1.;
+        // Keep the knockback or roll velocity until the end of the stagger or dodge
+        if staggered.is_some() || dodging.is_some() {
+            return Ok(());
         }
 
-        if keyboard_input.pressed(KeyCode::Up) || keyboard_input.pressed(KeyCode::W) {
-            velocity_vector.z = -1.;
+        // A stunned player cannot walk
+        if brain_state.conscious == ConsciousnessStateEnum::Stun {
+            velocity.linvel = Vec3::new(0., velocity.linvel.y, 0.);
+            return Ok(());
         }
 
-        if keyboard_input.pressed(KeyCode::Down) || keyboard_input.pressed(KeyCode::S) {
-            velocity_vector.z = 1.;
-        }
+        let velocity_vector = movement_direction(&keyboard_input);
 
         if velocity_vector.length() > 0. {
-            velocity.linvel = velocity_vector.normalize() * HUMAN_STEP_DISTANCE;
+            let mut speed = HUMAN_STEP_DISTANCE
+                * status_effects.speed_multiplier()
+                * regional_weather
+                    .at(transform.translation)
+                    .speed_multiplier();
+
+            // Sprint while holding shift, as long as the player is not exhausted
+            let sprinting = keyboard_input.pressed(KeyCode::LShift)
+                && stamina.try_consume(SPRINT_STAMINA_COST * time.delta_seconds());
+
+            if sprinting {
+                speed *= SPRINT_SPEED_FACTOR;
+            } else if stamina.exhausted {
+                speed *= EXHAUSTED_SPEED_FACTOR;
+            }
+
+            velocity.linvel =
+                ground.walk_velocity(velocity_vector.normalize() * speed, velocity.linvel);
         }
 
         // println!("Linear velocity is {:?}", velocity.linvel);
@@ -42,3 +97,96 @@ pub fn wasd_movement(
         println!("Error while handling click: {}", error);
     }
 }
+
+/// Direction pressed with the arrows or WASD keys, not normalized
+fn movement_direction(keyboard_input: &Input<KeyCode>) -> Vec3 {
+    let mut direction = Vec3::splat(0.);
+
+    if keyboard_input.pressed(KeyCode::Left) || keyboard_input.pressed(KeyCode::A) {
+        direction.x = -1.;
+    }
+
+    if keyboard_input.pressed(KeyCode::Right) || keyboard_input.pressed(KeyCode::D) {
+        direction.x = 1.;
+    }
+
+    if keyboard_input.pressed(KeyCode::Up) || keyboard_input.pressed(KeyCode::W) {
+        direction.z = -1.;
+    }
+
+    if keyboard_input.pressed(KeyCode::Down) || keyboard_input.pressed(KeyCode::S) {
+        direction.z = 1.;
+    }
+
+    direction
+}
+
+/**
+ * Dodge along the pressed movement direction with the space bar
+ */
+pub fn dodge_key(
+    keyboard_input: Res<Input<KeyCode>>,
+    player_q: Query<Entity, With<Player>>,
+    mut ev_dodge: EventWriter<DodgeEvent>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::Space) {
+        return;
+    }
+
+    let direction = movement_direction(&keyboard_input);
+    if direction.length() == 0. {
+        return;
+    }
+
+    if let Ok(player) = player_q.get_single() {
+        ev_dodge.send(DodgeEvent(player, direction));
+    }
+}
+
+/**
+ * Hold Q to raise the guard, releasing it lowers the guard
+ */
+pub fn block_key(
+    keyboard_input: Res<Input<KeyCode>>,
+    player_q: Query<Entity, With<Player>>,
+    mut ev_block: EventWriter<BlockEvent>,
+) {
+    let player = match player_q.get_single() {
+        Ok(player) => player,
+        Err(_) => return,
+    };
+
+    if keyboard_input.just_pressed(KeyCode::Q) {
+        ev_block.send(BlockEvent(player, true));
+    } else if keyboard_input.just_released(KeyCode::Q) {
+        ev_block.send(BlockEvent(player, false));
+    }
+}
+
+/**
+ * Jump with the F key, only from a ground flat enough to stand on
+ */
+pub fn jump_key(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut player_q: Query<
+        (&mut Velocity, &mut Stamina, &mut GroundContact),
+        (With<Player>, Without<Staggered>),
+    >,
+) {
+    if !keyboard_input.just_pressed(KeyCode::F) {
+        return;
+    }
+
+    let (mut velocity, mut stamina, mut ground) = match player_q.get_single_mut() {
+        Ok(player) => player,
+        Err(_) => return,
+    };
+
+    if !ground.grounded || ground.is_too_steep() || !stamina.try_consume(JUMP_STAMINA_COST) {
+        return;
+    }
+
+    velocity.linvel.y = JUMP_SPEED;
+    ground.grounded = false;
+    ground.jumping = true;
+}
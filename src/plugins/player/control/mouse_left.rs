@@ -2,84 +2,75 @@ use bevy::{
     input::{mouse::MouseButtonInput, ButtonState},
     prelude::*,
 };
-use bevy_rapier3d::prelude::*;
 
-use crate::{
-    plugins::{
-        combat::combat_events::HitMonsterEvent,
-        creature::{
-            creature_plugin::{Monster, Player},
-            systems::{inventory, sensors::PlayerSwordRangeSensor},
-        },
-        items::items_plugin::{AnimateVisualItem, EquippedItem, Inventory, Item, ItemMesh},
-    },
-    utils::error::ErrorMessage,
+use bevy_mod_raycast::RayCastSource;
+
+use crate::plugins::{
+    combat::combat_events::LockTargetEvent,
+    creature::creature_plugin::{Monster, Player},
+    items::items_plugin::{ChargeItemEvent, EquippedItem, ReleaseItemEvent},
 };
 
+use super::mouse::MouseRaycastSet;
+
+/**
+ * Charge the equipped item while the left button is held and release it with the button,
+ * its cooldown, swing and combo are handled by the activation
+ */
 pub fn mouse_left_click_system(
-    mut commands: Commands,
     mut mouse_button_input_events: EventReader<MouseButtonInput>,
-    rapier_context: Res<RapierContext>,
-    mut player_q: Query<(&Transform, &mut EquippedItem), (With<Player>, Without<ItemMesh>)>,
-    sword_q: Query<Entity, With<PlayerSwordRangeSensor>>,
-    monster_q: Query<Entity, With<Monster>>,
-    mut ev_hit_monster: EventWriter<HitMonsterEvent>,
+    player_q: Query<(Entity, &EquippedItem), With<Player>>,
+    mut ev_charge_item: EventWriter<ChargeItemEvent>,
+    mut ev_release_item: EventWriter<ReleaseItemEvent>,
 ) {
-    let mut closure = || {
-        for event in mouse_button_input_events.iter() {
-            // If not event Pressed we do nothing
-            if event.state != ButtonState::Pressed || event.button != MouseButton::Left {
-                return Ok(());
-            };
-
-            let (transform, mut equipped_item_entity) = player_q
-                .get_single_mut()
-                .expect("No Player found in left click");
-
-            let collider = sword_q.get_single().expect("No sword collider found");
+    for event in mouse_button_input_events.iter() {
+        if event.button != MouseButton::Left {
+            continue;
+        }
 
-            let equipped_visual_item_entity = match equipped_item_entity.0 {
-                Some(i) => i,
-                None => continue,
-            };
+        let (player, item) = match player_q.get_single() {
+            Ok((player, EquippedItem(Some(item)))) => (player, *item),
+            _ => continue,
+        };
 
-            println!(
-                "equipped_visual_item_entity -- {:?}",
-                equipped_visual_item_entity
-            );
+        match event.state {
+            ButtonState::Pressed => ev_charge_item.send(ChargeItemEvent(player, item)),
+            ButtonState::Released => ev_release_item.send(ReleaseItemEvent(player, item)),
+        }
+    }
+}
 
-            // TODO if !cooldown && !animation running
+/**
+ * Lock the monster under the cursor when clicking on it
+ */
+pub fn mouse_left_target_system(
+    mut mouse_button_input_events: EventReader<MouseButtonInput>,
+    raycast_q: Query<&RayCastSource<MouseRaycastSet>>,
+    player_q: Query<Entity, With<Player>>,
+    body_q: Query<&Parent>,
+    monster_q: Query<(), With<Monster>>,
+    mut ev_lock_target: EventWriter<LockTargetEvent>,
+) {
+    for event in mouse_button_input_events.iter() {
+        if event.button != MouseButton::Left || event.state != ButtonState::Pressed {
+            continue;
+        }
 
-            // Start animation for visual_equipped_item
-            commands
-                .entity(equipped_visual_item_entity)
-                .insert(AnimateVisualItem);
+        let player = match player_q.get_single() {
+            Ok(player) => player,
+            Err(_) => return,
+        };
 
-            /* Iterate through all the intersection pairs involving a specific collider. */
-            for (collider1, collider2, intersecting) in rapier_context.intersections_with(collider)
-            {
-                if intersecting {
-                    let victim = match monster_q.get(collider1) {
-                        Ok(m) => m,
-                        Err(_) => match monster_q.get(collider2) {
-                            Ok(m) => m,
-                            Err(_) => continue,
-                        },
-                    };
+        // The raycast hits the body mesh, a child of the monster
+        let monster = raycast_q
+            .iter()
+            .filter_map(|source| source.intersect_top())
+            .filter_map(|(entity, _)| body_q.get(entity).ok())
+            .map(|parent| parent.get())
+            .find(|entity| monster_q.contains(*entity));
 
-                    // Hit monster
-                    ev_hit_monster.send(HitMonsterEvent(
-                        victim,
-                        transform.rotation.to_axis_angle().1,
-                    ));
-                }
-            }
+        if let Some(monster) = monster {
+            ev_lock_target.send(LockTargetEvent(player, Some(monster)));
         }
-
-        Ok::<(), ErrorMessage>(())
-    };
-
-    if let Err(error) = closure() {
-        println!("Error while handling left click: {}", error);
     }
 }
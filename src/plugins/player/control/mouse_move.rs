@@ -2,16 +2,19 @@ use std::f32::consts::PI;
 
 use bevy::{
     input::mouse::MouseMotion,
-    prelude::{EventReader, Query, Transform, Vec3, With},
+    prelude::{EventReader, Query, Transform, Vec3, With, Without},
 };
 use bevy_mod_raycast::Intersection;
 
-use crate::plugins::creature::creature_plugin::Player;
+use crate::plugins::{combat::target::Target, creature::creature_plugin::Player};
 
 use super::mouse::MouseRaycastSet;
 
+/**
+ * Turn the player toward the mouse, unless a target is locked
+ */
 pub fn mouse_move_system(
-    mut q_player: Query<&mut Transform, With<Player>>,
+    mut q_player: Query<&mut Transform, (With<Player>, Without<Target>)>,
     mouse_pos_q: Query<&Intersection<MouseRaycastSet>>,
     mut mouse_motion_events: EventReader<MouseMotion>,
 ) {
@@ -24,9 +27,17 @@ pub fn mouse_move_system(
             Err(_) => return,
         };
 
-        let mut player_transform = q_player.get_single_mut().expect("No Player found");
+        let mut player_transform = match q_player.get_single_mut() {
+            Ok(transform) => transform,
+            Err(_) => return,
+        };
 
-        let looking_at = Vec3::new(mouse_position.x, 1., mouse_position.z);
+        // Look on the player height, the ground under the mouse is not flat
+        let looking_at = Vec3::new(
+            mouse_position.x,
+            player_transform.translation.y,
+            mouse_position.z,
+        );
         let mut sword_range_transform = Transform::from_translation(player_transform.translation);
         sword_range_transform.look_at(looking_at, Vec3::Y * 2.);
         sword_range_transform.rotate_y(PI / 2.);
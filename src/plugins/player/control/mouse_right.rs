@@ -1,28 +1,45 @@
 use bevy::{
     input::{mouse::MouseButtonInput, ButtonState},
-    prelude::{EventReader, EventWriter, MouseButton, Query},
+    prelude::{Entity, EventReader, EventWriter, MouseButton, Query, With},
 };
 use bevy_mod_raycast::Intersection;
 
-use crate::plugins::combat::combat_events::FireProjectileEvent;
+use crate::plugins::{
+    combat::combat_events::{DrawBowEvent, ReleaseBowEvent},
+    creature::creature_plugin::Player,
+};
 
 use super::mouse::MouseRaycastSet;
 
+/**
+ * Hold the right click to draw the equipped bow, release it to shoot toward the mouse
+ */
 pub fn mouse_right_click_system(
     mouse_pos_q: Query<&Intersection<MouseRaycastSet>>,
+    player_q: Query<Entity, With<Player>>,
     mut mouse_button_input_events: EventReader<MouseButtonInput>,
-    mut ev_fire_projectile: EventWriter<FireProjectileEvent>,
+    mut ev_draw_bow: EventWriter<DrawBowEvent>,
+    mut ev_release_bow: EventWriter<ReleaseBowEvent>,
 ) {
     for event in mouse_button_input_events.iter() {
-        // If not event Pressed we do nothing
-        if event.state == ButtonState::Pressed && event.button == MouseButton::Right {
-            match mouse_pos_q.get_single() {
-                Ok(p) => match p.position() {
-                    Some(p) => ev_fire_projectile.send(FireProjectileEvent(p.clone())),
-                    None => return,
-                },
-                Err(_) => return,
-            };
+        if event.button != MouseButton::Right {
+            continue;
+        }
+
+        let player = match player_q.get_single() {
+            Ok(player) => player,
+            Err(_) => return,
+        };
+
+        match event.state {
+            ButtonState::Pressed => ev_draw_bow.send(DrawBowEvent(player)),
+            ButtonState::Released => {
+                let target = match mouse_pos_q.get_single() {
+                    Ok(p) => p.position().copied(),
+                    Err(_) => None,
+                };
+                ev_release_bow.send(ReleaseBowEvent(player, target));
+            }
         };
     }
 }
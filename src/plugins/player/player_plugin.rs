@@ -4,8 +4,10 @@ use crate::GameState;
 
 use super::{
     control::{
-        keyboard_actions::equip_item_key, keyboard_movement::wasd_movement,
-        mouse_left::mouse_left_click_system, mouse_move::mouse_move_system,
+        keyboard_actions::{belt_keys, crafting_menu_key, interact_key, stack_keys, target_keys},
+        keyboard_movement::{block_key, dodge_key, jump_key, wasd_movement},
+        mouse_left::{mouse_left_click_system, mouse_left_target_system},
+        mouse_move::mouse_move_system,
         mouse_right::mouse_right_click_system,
     },
     player_events::{KillPlayerEvent, RespawnPlayerEvent},
@@ -23,7 +25,15 @@ impl Plugin for PlayerPlugin {
             .add_system(mouse_right_click_system)
             .add_system(mouse_left_click_system)
             .add_system(mouse_move_system)
-            .add_system(equip_item_key);
+            .add_system(interact_key)
+            .add_system(crafting_menu_key)
+            .add_system(belt_keys)
+            .add_system(stack_keys)
+            .add_system(dodge_key)
+            .add_system(block_key)
+            .add_system(jump_key)
+            .add_system(target_keys)
+            .add_system(mouse_left_target_system);
         // .add_system(mouse_left_click_system)
         // .add_system(kill_player)
         // .add_system(respawn_player);
@@ -0,0 +1,102 @@
+use std::f32::consts::TAU;
+
+use crate::{DAY_LENGTH, DAY_START_TIME};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DayPeriod {
+    Day,
+    Night,
+}
+
+/// # WorldClock
+/// Time of the world, advanced by the world_clock_system
+///
+/// The time of day goes from 0 to 1: 0 is midnight, 0.25 sunrise, 0.5 noon and 0.75 sunset.
+/// The clock only moves with `advance`, so it can be driven without a window or a Time resource
+///
+#[derive(Clone, Copy, Debug)]
+pub struct WorldClock {
+    /// Seconds since the first midnight
+    pub elapsed: f32,
+    /// Seconds in a full day
+    pub day_length: f32,
+}
+
+impl Default for WorldClock {
+    fn default() -> Self {
+        Self::new(DAY_LENGTH, DAY_START_TIME)
+    }
+}
+
+impl WorldClock {
+    pub fn new(day_length: f32, time_of_day: f32) -> Self {
+        Self {
+            elapsed: time_of_day * day_length,
+            day_length,
+        }
+    }
+
+    pub fn advance(&mut self, seconds: f32) {
+        self.elapsed += seconds;
+    }
+
+    /// Number of midnights since the start
+    pub fn day(&self) -> u32 {
+        (self.elapsed / self.day_length) as u32
+    }
+
+    pub fn time_of_day(&self) -> f32 {
+        (self.elapsed / self.day_length).fract()
+    }
+
+    pub fn period(&self) -> DayPeriod {
+        match self.time_of_day() {
+            time if (0.25..0.75).contains(&time) => DayPeriod::Day,
+            _ => DayPeriod::Night,
+        }
+    }
+
+    /// Strength of the sun, 0 from sunset to sunrise and 1 at noon
+    pub fn daylight(&self) -> f32 {
+        ((self.time_of_day() - 0.25) * TAU).sin().max(0.)
+    }
+
+    /// Angle of the sun above the horizon, rising at sunrise and setting at sunset
+    pub fn sun_angle(&self) -> f32 {
+        (self.time_of_day() - 0.25) * TAU
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advance_crosses_sunrise() {
+        let mut clock = WorldClock::new(100., 0.2);
+        assert_eq!(clock.period(), DayPeriod::Night);
+        assert_eq!(clock.daylight(), 0.);
+
+        clock.advance(10.);
+        assert_eq!(clock.period(), DayPeriod::Day);
+        assert!(clock.daylight() > 0.);
+        assert_eq!(clock.day(), 0);
+    }
+
+    #[test]
+    fn advance_crosses_sunset_and_midnight() {
+        let mut clock = WorldClock::new(100., 0.5);
+        assert_eq!(clock.period(), DayPeriod::Day);
+        assert!((clock.daylight() - 1.).abs() < 1e-4);
+
+        clock.advance(30.);
+        assert_eq!(clock.period(), DayPeriod::Night);
+        assert_eq!(clock.daylight(), 0.);
+        assert_eq!(clock.day(), 0);
+
+        clock.advance(30.);
+        assert_eq!(clock.period(), DayPeriod::Night);
+        assert_eq!(clock.day(), 1);
+        assert!((clock.time_of_day() - 0.1).abs() < 1e-4);
+    }
+}
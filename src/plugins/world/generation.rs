@@ -0,0 +1,521 @@
+use bevy::prelude::*;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::Deserialize;
+use std::{collections::HashSet, ops::RangeInclusive};
+
+use crate::{
+    plugins::{creature::creature_plugin::Activity, farming::resource_nodes::ResourceNodeType},
+    BIOME_REGION_CHUNKS, CHUNK_SIZE, GROUND_LEVEL, SPAWN_ZONE_RADIUS, TERRAIN_LATTICE_SIZE,
+    WORLD_CHUNK_RADIUS, WORLD_MIN_SPACING,
+};
+
+/// Salts keeping the random sequences of a chunk independent from each other
+const BIOME_SALT: u64 = 1;
+const PLACEMENT_SALT: u64 = 2;
+const HEIGHT_SALT: u64 = 3;
+
+/// Distance kept between the placements and the chunk borders
+const CHUNK_MARGIN: f32 = 2.;
+
+/// Position of a chunk in the world grid, chunk (0, 0) is centered on the origin
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ChunkCoord {
+    pub x: i32,
+    pub z: i32,
+}
+
+impl ChunkCoord {
+    pub const fn new(x: i32, z: i32) -> Self {
+        Self { x, z }
+    }
+
+    /// Chunk containing a world position
+    pub fn from_position(position: Vec3) -> Self {
+        Self {
+            x: (position.x / CHUNK_SIZE).round() as i32,
+            z: (position.z / CHUNK_SIZE).round() as i32,
+        }
+    }
+
+    /// World position of the chunk center, on the ground plane
+    pub fn center(&self) -> Vec3 {
+        Vec3::new(self.x as f32 * CHUNK_SIZE, 0., self.z as f32 * CHUNK_SIZE)
+    }
+
+    /// Number of chunks between both chunks, diagonals included
+    pub fn distance(&self, other: ChunkCoord) -> i32 {
+        (self.x - other.x).abs().max((self.z - other.z).abs())
+    }
+
+    /// Chunks at most radius chunks away from this one, itself included
+    pub fn around(&self, radius: i32) -> impl Iterator<Item = ChunkCoord> {
+        let center = *self;
+        (-radius..=radius).flat_map(move |x| {
+            (-radius..=radius).map(move |z| ChunkCoord::new(center.x + x, center.z + z))
+        })
+    }
+
+    /// Region of BIOME_REGION_CHUNKS x BIOME_REGION_CHUNKS chunks containing the chunk,
+    /// sharing the same biome and weather
+    pub fn region(&self) -> ChunkCoord {
+        ChunkCoord::new(
+            self.x.div_euclid(BIOME_REGION_CHUNKS),
+            self.z.div_euclid(BIOME_REGION_CHUNKS),
+        )
+    }
+
+    /// The chunk the player starts in, kept free of obstacles and monsters
+    pub fn is_spawn(&self) -> bool {
+        *self == ChunkCoord::default()
+    }
+}
+
+/// Point of the terrain height grid, spaced by TERRAIN_LATTICE_SIZE
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LatticePoint {
+    pub x: i32,
+    pub z: i32,
+}
+
+impl LatticePoint {
+    pub const fn new(x: i32, z: i32) -> Self {
+        Self { x, z }
+    }
+
+    /// World position of the point, on the ground plane
+    pub fn position(&self) -> Vec3 {
+        Vec3::new(self.x as f32, 0., self.z as f32) * TERRAIN_LATTICE_SIZE
+    }
+
+    /// Chunks containing the point, a point on a chunk border belongs to the chunks on both sides
+    pub fn chunks(&self) -> impl Iterator<Item = ChunkCoord> {
+        let position = self.position();
+        let half = CHUNK_SIZE / 2.;
+        let chunks_along = |value: f32| {
+            ((value - half) / CHUNK_SIZE).ceil() as i32
+                ..=((value + half) / CHUNK_SIZE).floor() as i32
+        };
+
+        let zs = chunks_along(position.z);
+        chunks_along(position.x).flat_map(move |x| zs.clone().map(move |z| ChunkCoord::new(x, z)))
+    }
+}
+
+// ------------------
+//
+// Biome
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Biome {
+    Plains,
+    Forest,
+    Hills,
+    Swamp,
+}
+
+impl Biome {
+    pub const ALL: [Biome; 4] = [Biome::Plains, Biome::Forest, Biome::Hills, Biome::Swamp];
+
+    /// Relative chance of a region to be of this biome
+    pub fn weight(&self) -> u32 {
+        match self {
+            Biome::Plains => 4,
+            Biome::Forest => 3,
+            Biome::Hills => 2,
+            Biome::Swamp => 1,
+        }
+    }
+
+    pub fn ground_color(&self) -> Color {
+        match self {
+            Biome::Plains => Color::rgb(0.3, 0.5, 0.3),
+            Biome::Forest => Color::rgb(0.15, 0.35, 0.15),
+            Biome::Hills => Color::rgb(0.45, 0.42, 0.35),
+            Biome::Swamp => Color::rgb(0.3, 0.35, 0.2),
+        }
+    }
+
+    /// Maximum height of the hills of the biome above GROUND_LEVEL
+    pub fn height_amplitude(&self) -> f32 {
+        match self {
+            Biome::Plains => 1.5,
+            Biome::Forest => 3.,
+            Biome::Hills => 8.,
+            Biome::Swamp => 0.5,
+        }
+    }
+
+    /// Number of resource nodes of each type in a chunk
+    pub fn resource_nodes(&self) -> Vec<(ResourceNodeType, RangeInclusive<u32>)> {
+        match self {
+            Biome::Plains => vec![
+                (ResourceNodeType::Plant, 2..=4),
+                (ResourceNodeType::Tree, 0..=1),
+                (ResourceNodeType::Rock, 0..=1),
+            ],
+            Biome::Forest => vec![
+                (ResourceNodeType::Tree, 3..=5),
+                (ResourceNodeType::Plant, 1..=2),
+            ],
+            Biome::Hills => vec![
+                (ResourceNodeType::Rock, 2..=3),
+                (ResourceNodeType::IronOre, 1..=2),
+            ],
+            Biome::Swamp => vec![
+                (ResourceNodeType::Plant, 2..=4),
+                (ResourceNodeType::Tree, 1..=1),
+            ],
+        }
+    }
+
+    /// Number of obstacles of each type in a chunk
+    pub fn obstacles(&self) -> Vec<(ObstacleType, RangeInclusive<u32>)> {
+        match self {
+            Biome::Plains => vec![(ObstacleType::Boulder, 0..=1)],
+            Biome::Forest => vec![(ObstacleType::Tree, 2..=4)],
+            Biome::Hills => vec![(ObstacleType::Boulder, 2..=3), (ObstacleType::Wall, 0..=2)],
+            Biome::Swamp => vec![(ObstacleType::Tree, 1..=2)],
+        }
+    }
+
+    /// Period of the day when the monsters of the biome are out
+    pub fn spawn_activity(&self) -> Activity {
+        match self {
+            Biome::Plains | Biome::Hills => Activity::Diurnal,
+            Biome::Forest | Biome::Swamp => Activity::Nocturnal,
+        }
+    }
+
+    /// Chance of a chunk to hold a monster spawn zone, and the monsters it holds
+    pub fn spawn_zone(&self) -> (f32, RangeInclusive<u32>) {
+        match self {
+            Biome::Plains => (0.3, 2..=4),
+            Biome::Forest => (0.5, 3..=5),
+            Biome::Hills => (0.4, 2..=4),
+            Biome::Swamp => (0.6, 4..=6),
+        }
+    }
+}
+
+// ------------------
+//
+// ObstacleType
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
+pub enum ObstacleType {
+    Tree,
+    Boulder,
+    Wall,
+}
+
+impl ObstacleType {
+    pub fn size(&self) -> Vec3 {
+        match self {
+            ObstacleType::Tree => Vec3::new(1.2, 6., 1.2),
+            ObstacleType::Boulder => Vec3::new(3., 2., 3.),
+            ObstacleType::Wall => Vec3::new(8., 3., 1.),
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            ObstacleType::Tree => Color::rgb(0.3, 0.2, 0.1),
+            ObstacleType::Boulder => Color::DARK_GRAY,
+            ObstacleType::Wall => Color::rgb(0.5, 0.5, 0.55),
+        }
+    }
+}
+
+/// An obstacle of the layout, rotated around the vertical axis
+#[derive(Clone, Debug, PartialEq)]
+pub struct ObstacleLayout {
+    pub obstacle_type: ObstacleType,
+    pub position: Vec2,
+    pub rotation: f32,
+}
+
+/// Area where monsters are spawned
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpawnZoneLayout {
+    pub center: Vec2,
+    pub radius: f32,
+    pub monsters: u32,
+    pub activity: Activity,
+}
+
+/// Everything generated in a chunk, positions are horizontal world positions
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChunkLayout {
+    pub coord: ChunkCoord,
+    pub biome: Biome,
+    pub resource_nodes: Vec<(ResourceNodeType, Vec2)>,
+    pub obstacles: Vec<ObstacleLayout>,
+    pub spawn_zones: Vec<SpawnZoneLayout>,
+}
+
+/// Deterministic world generator, the same seed always produces the same chunks
+///
+/// Each chunk is generated from its own random sequence, so chunks can be generated
+/// in any order and independently from each other
+#[derive(Clone, Debug)]
+pub struct WorldGenerator {
+    pub seed: u64,
+    /// Chunks kept at GROUND_LEVEL, e.g. the ones covered by a map, the spawn chunk is always flat
+    pub flat_chunks: HashSet<ChunkCoord>,
+}
+
+impl WorldGenerator {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            flat_chunks: HashSet::new(),
+        }
+    }
+
+    /// Height of the terrain at a horizontal world position
+    ///
+    /// The terrain interpolates random heights placed on a grid of TERRAIN_LATTICE_SIZE,
+    /// scaled by the biome amplitude, so it is continuous across chunk borders
+    pub fn ground_height(&self, position: Vec2) -> f32 {
+        let cell = position / TERRAIN_LATTICE_SIZE;
+        let corner = cell.floor();
+        let t = cell - corner;
+        // Smoothstep, to avoid creases along the grid lines
+        let t = t * t * (Vec2::splat(3.) - 2. * t);
+
+        let height = |x: i32, z: i32| {
+            self.lattice_height(LatticePoint::new(corner.x as i32 + x, corner.y as i32 + z))
+        };
+        let near = height(0, 0) + (height(1, 0) - height(0, 0)) * t.x;
+        let far = height(0, 1) + (height(1, 1) - height(0, 1)) * t.x;
+
+        GROUND_LEVEL + near + (far - near) * t.y
+    }
+
+    /// Normal of the terrain at a horizontal world position
+    pub fn ground_normal(&self, position: Vec2) -> Vec3 {
+        let step = 0.5;
+        let dx = self.ground_height(position + Vec2::X * step)
+            - self.ground_height(position - Vec2::X * step);
+        let dz = self.ground_height(position + Vec2::Y * step)
+            - self.ground_height(position - Vec2::Y * step);
+
+        Vec3::new(-dx, 2. * step, -dz).normalize()
+    }
+
+    /// Random height of a point of the terrain grid, zero on and inside the flat chunks
+    fn lattice_height(&self, point: LatticePoint) -> f32 {
+        let flat = point
+            .chunks()
+            .any(|chunk| chunk == ChunkCoord::default() || self.flat_chunks.contains(&chunk));
+        if flat {
+            return 0.;
+        }
+
+        let position = point.position();
+        let amplitude = self
+            .biome(ChunkCoord::from_position(position))
+            .height_amplitude();
+        // Mix the hash so close points get unrelated heights,
+        // then take its top 24 bits as a value between 0 and 1
+        let mut hash = self.hash(point.x, point.z, HEIGHT_SALT);
+        hash ^= hash >> 33;
+        hash = hash.wrapping_mul(0xFF51_AFD7_ED55_8CCD);
+        hash ^= hash >> 33;
+        let value = (hash >> 40) as f32 / (1 << 24) as f32;
+
+        value * amplitude
+    }
+
+    /// Every chunk of the world, within WORLD_CHUNK_RADIUS of the spawn chunk
+    pub fn contains(&self, coord: ChunkCoord) -> bool {
+        coord.distance(ChunkCoord::default()) <= WORLD_CHUNK_RADIUS
+    }
+
+    /// Distance between the world center and its borders, along the x and z axes
+    pub fn half_extent(&self) -> f32 {
+        (WORLD_CHUNK_RADIUS as f32 + 0.5) * CHUNK_SIZE
+    }
+
+    /// Position moved back inside the world borders, the height is kept
+    pub fn clamp(&self, position: Vec3) -> Vec3 {
+        let half_extent = self.half_extent();
+
+        Vec3::new(
+            position.x.clamp(-half_extent, half_extent),
+            position.y,
+            position.z.clamp(-half_extent, half_extent),
+        )
+    }
+
+    /// Biomes span regions of BIOME_REGION_CHUNKS x BIOME_REGION_CHUNKS chunks
+    pub fn biome(&self, coord: ChunkCoord) -> Biome {
+        let mut rng = self.rng(coord.region(), BIOME_SALT);
+
+        let total: u32 = Biome::ALL.iter().map(|biome| biome.weight()).sum();
+        let mut roll = rng.gen_range(0..total);
+
+        for biome in Biome::ALL {
+            if roll < biome.weight() {
+                return biome;
+            }
+            roll -= biome.weight();
+        }
+
+        Biome::Plains
+    }
+
+    pub fn chunk(&self, coord: ChunkCoord) -> ChunkLayout {
+        let biome = self.biome(coord);
+        let mut rng = self.rng(coord, PLACEMENT_SALT);
+        let mut taken: Vec<Vec2> = Vec::new();
+
+        let mut resource_nodes = Vec::new();
+        for (node_type, count) in biome.resource_nodes() {
+            for _ in 0..rng.gen_range(count) {
+                if let Some(position) = place(&mut rng, coord, &mut taken) {
+                    resource_nodes.push((node_type, position));
+                }
+            }
+        }
+
+        let mut obstacles = Vec::new();
+        let mut spawn_zones = Vec::new();
+
+        // The player starts in a clear chunk
+        if !coord.is_spawn() {
+            for (obstacle_type, count) in biome.obstacles() {
+                for _ in 0..rng.gen_range(count) {
+                    if let Some(position) = place(&mut rng, coord, &mut taken) {
+                        obstacles.push(ObstacleLayout {
+                            obstacle_type,
+                            position,
+                            rotation: rng.gen_range(0..4) as f32 * std::f32::consts::FRAC_PI_2,
+                        });
+                    }
+                }
+            }
+
+            let (chance, monsters) = biome.spawn_zone();
+            if rng.gen::<f32>() < chance {
+                spawn_zones.push(SpawnZoneLayout {
+                    center: random_position(&mut rng, coord, SPAWN_ZONE_RADIUS),
+                    radius: SPAWN_ZONE_RADIUS,
+                    monsters: rng.gen_range(monsters),
+                    activity: biome.spawn_activity(),
+                });
+            }
+        }
+
+        ChunkLayout {
+            coord,
+            biome,
+            resource_nodes,
+            obstacles,
+            spawn_zones,
+        }
+    }
+
+    /// ChaCha8 keeps its algorithm across releases, unlike StdRng, so a seed keeps its world
+    fn rng(&self, coord: ChunkCoord, salt: u64) -> ChaCha8Rng {
+        ChaCha8Rng::seed_from_u64(self.hash(coord.x, coord.z, salt))
+    }
+
+    fn hash(&self, x: i32, z: i32, salt: u64) -> u64 {
+        let mut hash = self.seed ^ salt.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        hash ^= (x as i64 as u64).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        hash = hash.rotate_left(31);
+        hash ^ (z as i64 as u64).wrapping_mul(0x94D0_49BB_1331_11EB)
+    }
+}
+
+/// Random horizontal position in the chunk, away from its borders
+fn random_position(rng: &mut ChaCha8Rng, coord: ChunkCoord, margin: f32) -> Vec2 {
+    let half = CHUNK_SIZE / 2. - margin;
+    let center = coord.center();
+
+    Vec2::new(
+        center.x + rng.gen_range(-half..=half),
+        center.z + rng.gen_range(-half..=half),
+    )
+}
+
+/// Position away from the previous placements of the chunk, None if the chunk is too crowded
+fn place(rng: &mut ChaCha8Rng, coord: ChunkCoord, taken: &mut Vec<Vec2>) -> Option<Vec2> {
+    for _ in 0..10 {
+        let position = random_position(rng, coord, CHUNK_MARGIN);
+
+        if taken
+            .iter()
+            .all(|other| other.distance(position) >= WORLD_MIN_SPACING)
+        {
+            taken.push(position);
+            return Some(position);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunks_around_spawn() -> impl Iterator<Item = ChunkCoord> {
+        (-3..=3).flat_map(|x| (-3..=3).map(move |z| ChunkCoord::new(x, z)))
+    }
+
+    #[test]
+    fn same_seed_gives_same_layout() {
+        let generator = WorldGenerator::new(42);
+        let other = WorldGenerator::new(42);
+
+        for coord in chunks_around_spawn() {
+            assert_eq!(generator.biome(coord), other.biome(coord));
+            assert_eq!(generator.chunk(coord), other.chunk(coord));
+        }
+    }
+
+    #[test]
+    fn different_seed_gives_different_layout() {
+        let generator = WorldGenerator::new(42);
+        let other = WorldGenerator::new(43);
+
+        let layouts: Vec<ChunkLayout> = chunks_around_spawn()
+            .map(|coord| generator.chunk(coord))
+            .collect();
+        let other_layouts: Vec<ChunkLayout> = chunks_around_spawn()
+            .map(|coord| other.chunk(coord))
+            .collect();
+
+        assert_ne!(layouts, other_layouts);
+    }
+
+    #[test]
+    fn spawn_chunk_is_clear() {
+        for seed in 0..20 {
+            let layout = WorldGenerator::new(seed).chunk(ChunkCoord::default());
+
+            assert!(layout.obstacles.is_empty());
+            assert!(layout.spawn_zones.is_empty());
+        }
+    }
+
+    #[test]
+    fn flat_chunks_are_flat_up_to_their_borders() {
+        let mut generator = WorldGenerator::new(42);
+        let chunk = ChunkCoord::new(2, -1);
+        generator.flat_chunks.insert(chunk);
+
+        let center = chunk.center();
+        let half = CHUNK_SIZE / 2.;
+        for offset in [
+            Vec2::ZERO,
+            Vec2::new(half, half),
+            Vec2::new(-half, 0.),
+            Vec2::new(3., -half),
+        ] {
+            let position = Vec2::new(center.x, center.z) + offset;
+            assert_eq!(generator.ground_height(position), GROUND_LEVEL);
+        }
+    }
+}
@@ -0,0 +1,220 @@
+use std::fs;
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::{
+    plugins::{
+        combat::weapons::range::projectile::ProjectileType,
+        creature::creature_plugin::{Activity, CreatureType},
+        farming::resource_nodes::ResourceNodeType,
+        interaction::interactables::LootTable,
+        items::items_plugin::ItemType,
+    },
+    utils::{assets::asset_path, error::ErrorMessage},
+    MAPS_FOLDER,
+};
+
+use super::generation::{ChunkCoord, ObstacleType};
+
+/// # MapFile
+/// Hand-authored area loaded from `assets/maps/<name>.ron`
+///
+/// Horizontal positions are `(x, z)` positions from the origin of the zone of the map,
+/// the world origin for the overworld maps, maps are flat at GROUND_LEVEL.
+/// The chunks listed in `chunks` are not generated and the terrain around them is flattened,
+/// the map is the only content spawned there
+///
+#[derive(Debug, Deserialize)]
+pub struct MapFile {
+    pub name: String,
+    #[serde(default)]
+    pub chunks: Vec<(i32, i32)>,
+    #[serde(default)]
+    pub ground: Vec<GroundTile>,
+    #[serde(default)]
+    pub colliders: Vec<StaticCollider>,
+    #[serde(default)]
+    pub props: Vec<Prop>,
+    #[serde(default)]
+    pub resource_nodes: Vec<ResourceNodePlacement>,
+    #[serde(default)]
+    pub spawn_zones: Vec<SpawnZonePlacement>,
+    #[serde(default)]
+    pub safe_zones: Vec<SafeZonePlacement>,
+    #[serde(default)]
+    pub portals: Vec<PortalPlacement>,
+    #[serde(default)]
+    pub chests: Vec<ChestPlacement>,
+    #[serde(default)]
+    pub doors: Vec<DoorPlacement>,
+    #[serde(default)]
+    pub levers: Vec<LeverPlacement>,
+    #[serde(default)]
+    pub spawn_points: Vec<SpawnPointPlacement>,
+    #[serde(default)]
+    pub items: Vec<ItemPlacement>,
+    #[serde(default)]
+    pub npcs: Vec<NpcPlacement>,
+}
+
+impl MapFile {
+    pub fn load(name: &str) -> Result<Self, ErrorMessage> {
+        let content = fs::read_to_string(asset_path(MAPS_FOLDER, name))?;
+        Ok(ron::from_str(&content)?)
+    }
+
+    pub fn chunk_coords(&self) -> impl Iterator<Item = ChunkCoord> + '_ {
+        self.chunks.iter().map(|(x, z)| ChunkCoord::new(*x, *z))
+    }
+}
+
+/// Flat ground rectangle, walkable and hit by the mouse raycast
+#[derive(Debug, Deserialize)]
+pub struct GroundTile {
+    pub position: Vec2,
+    pub size: Vec2,
+    pub color: [f32; 3],
+}
+
+/// Invisible wall, e.g. to close the borders of a dungeon
+#[derive(Debug, Deserialize)]
+pub struct StaticCollider {
+    pub position: Vec2,
+    pub size: Vec3,
+    #[serde(default)]
+    pub rotation: f32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Prop {
+    pub obstacle: ObstacleType,
+    pub position: Vec2,
+    #[serde(default)]
+    pub rotation: f32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResourceNodePlacement {
+    pub node: ResourceNodeType,
+    pub position: Vec2,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SpawnZonePlacement {
+    pub position: Vec2,
+    pub radius: f32,
+    pub monsters: u32,
+    #[serde(default = "default_spawn_activity")]
+    pub activity: Activity,
+}
+
+fn default_spawn_activity() -> Activity {
+    CreatureType::Monster.activity()
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SafeZonePlacement {
+    pub position: Vec2,
+    pub radius: f32,
+}
+
+/// Area moving the players walking in to a spawn point of a zone
+#[derive(Debug, Deserialize)]
+pub struct PortalPlacement {
+    pub position: Vec2,
+    #[serde(default = "default_portal_radius")]
+    pub radius: f32,
+    pub zone: String,
+    pub spawn_point: String,
+}
+
+fn default_portal_radius() -> f32 {
+    1.5
+}
+
+/// Named position where the players arrive in the zone of the map
+#[derive(Debug, Deserialize)]
+pub struct SpawnPointPlacement {
+    pub name: String,
+    pub position: Vec2,
+}
+
+/// Chest holding the items rolled from its loot table, locked when a key is given
+#[derive(Debug, Deserialize)]
+pub struct ChestPlacement {
+    pub position: Vec2,
+    #[serde(default)]
+    pub rotation: f32,
+    #[serde(default)]
+    pub loot: LootTable,
+    #[serde(default)]
+    pub key: Option<ItemType>,
+}
+
+/// Door toggled by the players, or only by the levers targeting its id when not interactable
+///
+/// Locked doors only open with their key, the levers cannot target them
+#[derive(Debug, Deserialize)]
+pub struct DoorPlacement {
+    #[serde(default)]
+    pub id: Option<String>,
+    pub position: Vec2,
+    #[serde(default = "default_door_size")]
+    pub size: Vec3,
+    #[serde(default)]
+    pub rotation: f32,
+    #[serde(default)]
+    pub open: bool,
+    #[serde(default = "default_interactable")]
+    pub interactable: bool,
+    #[serde(default)]
+    pub key: Option<ItemType>,
+}
+
+fn default_door_size() -> Vec3 {
+    Vec3::new(3., 3., 0.4)
+}
+
+fn default_interactable() -> bool {
+    true
+}
+
+/// Lever toggling the doors with the target ids
+#[derive(Debug, Deserialize)]
+pub struct LeverPlacement {
+    pub position: Vec2,
+    pub targets: Vec<String>,
+}
+
+/// Item lying in the world, placed at a 3D position so it can sit on a prop
+#[derive(Debug, Deserialize)]
+pub struct ItemPlacement {
+    pub item: ItemType,
+    #[serde(default = "default_quantity")]
+    pub quantity: u32,
+    pub position: Vec3,
+}
+
+fn default_quantity() -> u32 {
+    1
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NpcPlacement {
+    pub creature: CreatureType,
+    pub name: String,
+    pub position: Vec2,
+    pub hp: f32,
+    /// Defaults to the attack of the creature type
+    #[serde(default)]
+    pub atk: Option<f32>,
+    #[serde(default)]
+    pub ranged_attack: Option<ProjectileType>,
+    /// Defaults to the activity of the creature type
+    #[serde(default)]
+    pub activity: Option<Activity>,
+    /// Defaults to true for monsters, peaceful NPCs never aggro
+    #[serde(default)]
+    pub hostile: Option<bool>,
+}
@@ -0,0 +1,76 @@
+use std::collections::{HashMap, HashSet};
+
+use bevy::prelude::*;
+
+use crate::{GROUND_LEVEL, STARTING_MAPS};
+
+use super::{
+    generation::ChunkCoord,
+    map::MapFile,
+    systems::{
+        spawn_map::spawn_maps_system,
+        zones::{
+            enter_zone_system, load_zone_files_system, portal_cooldown_system, portal_system,
+            unload_zones_system,
+        },
+    },
+    world_events::EnterZoneEvent,
+    zone::{LoadedZones, ZoneStore, Zones},
+};
+
+pub struct MapPlugin;
+impl Plugin for MapPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Maps>()
+            .init_resource::<MapChunks>()
+            .init_resource::<SpawnPoints>()
+            .init_resource::<Zones>()
+            .init_resource::<LoadedZones>()
+            .init_resource::<ZoneStore>()
+            .add_event::<EnterZoneEvent>()
+            // The map chunks have to be known before the world streams its first chunks
+            .add_startup_system_to_stage(StartupStage::PreStartup, spawn_maps_system)
+            .add_startup_system(load_zone_files_system)
+            .add_system(portal_system)
+            .add_system(portal_cooldown_system)
+            .add_system(enter_zone_system)
+            .add_system(unload_zones_system);
+    }
+}
+
+/// Names of the maps loaded at startup, from `assets/maps/<name>.ron`
+pub struct Maps(pub Vec<String>);
+
+impl Default for Maps {
+    fn default() -> Self {
+        Self(STARTING_MAPS.iter().map(|name| name.to_string()).collect())
+    }
+}
+
+/// Chunks covered by a map, never generated
+#[derive(Default)]
+pub struct MapChunks(pub HashSet<ChunkCoord>);
+
+/// Ground positions of the spawn points of the loaded zones, by zone and spawn point name
+#[derive(Default)]
+pub struct SpawnPoints(pub HashMap<(String, String), Vec3>);
+
+impl SpawnPoints {
+    pub fn register(&mut self, zone: &str, map: &MapFile, origin: Vec3) {
+        for spawn_point in map.spawn_points.iter() {
+            self.0.insert(
+                (zone.to_string(), spawn_point.name.clone()),
+                origin + Vec3::new(spawn_point.position.x, GROUND_LEVEL, spawn_point.position.y),
+            );
+        }
+    }
+
+    pub fn get(&self, zone: &str, name: &str) -> Option<Vec3> {
+        self.0.get(&(zone.to_string(), name.to_string())).copied()
+    }
+
+    /// Forget the spawn points of an unloaded zone
+    pub fn remove_zone(&mut self, zone: &str) {
+        self.0.retain(|(spawn_zone, _), _| spawn_zone != zone);
+    }
+}
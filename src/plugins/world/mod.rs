@@ -0,0 +1,9 @@
+pub mod clock;
+pub mod generation;
+pub mod map;
+pub mod map_plugin;
+pub mod systems;
+pub mod weather;
+pub mod world_events;
+pub mod world_plugin;
+pub mod zone;
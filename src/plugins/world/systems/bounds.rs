@@ -0,0 +1,75 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+use crate::{
+    plugins::{
+        creature::creature_plugin::Creature,
+        world::{generation::WorldGenerator, world_plugin::Dormant, zone::Zones},
+    },
+    GROUND_LEVEL, WORLD_WALL_HEIGHT,
+};
+
+/// Invisible wall along a border of the world
+#[derive(Component)]
+pub struct WorldWall;
+
+/**
+ * Close the world with an invisible wall on each of its four borders
+ */
+pub fn spawn_world_walls_system(mut commands: Commands, generator: Res<WorldGenerator>) {
+    let half_extent = generator.half_extent();
+
+    for wall in spawn_walls(&mut commands, Vec3::ZERO, Vec2::splat(half_extent)) {
+        commands.entity(wall).insert(WorldWall);
+    }
+}
+
+/// Spawn an invisible wall on each border of the rectangle centered on the position
+pub fn spawn_walls(commands: &mut Commands, center: Vec3, half_size: Vec2) -> Vec<Entity> {
+    [
+        (Vec3::X, Vec3::new(0.5, 0., half_size.y)),
+        (-Vec3::X, Vec3::new(0.5, 0., half_size.y)),
+        (Vec3::Z, Vec3::new(half_size.x, 0., 0.5)),
+        (-Vec3::Z, Vec3::new(half_size.x, 0., 0.5)),
+    ]
+    .into_iter()
+    .map(|(side, wall_half_size)| {
+        let distance = Vec3::new(half_size.x + 0.5, 0., half_size.y + 0.5);
+        let translation = center + side * distance + Vec3::Y * GROUND_LEVEL;
+
+        commands
+            .spawn_bundle(TransformBundle::from(Transform::from_translation(
+                translation,
+            )))
+            .insert(Collider::cuboid(
+                wall_half_size.x,
+                WORLD_WALL_HEIGHT / 2.,
+                wall_half_size.z,
+            ))
+            .id()
+    })
+    .collect()
+}
+
+/**
+ * Bring back the creatures which went through the walls of the world or of their zone,
+ * e.g. pushed by a knockback or a roll
+ */
+pub fn keep_in_bounds_system(
+    generator: Res<WorldGenerator>,
+    zones: Res<Zones>,
+    mut creature_q: Query<(&mut Transform, &mut Velocity), (With<Creature>, Without<Dormant>)>,
+) {
+    for (mut transform, mut velocity) in creature_q.iter_mut() {
+        let clamped = match zones.at(transform.translation) {
+            Some(zone) => zone.clamp(transform.translation),
+            None => generator.clamp(transform.translation),
+        };
+        if clamped == transform.translation {
+            continue;
+        }
+
+        transform.translation = clamped;
+        velocity.linvel = Vec3::new(0., velocity.linvel.y, 0.);
+    }
+}
@@ -0,0 +1,155 @@
+use bevy::prelude::*;
+
+use crate::{
+    plugins::{
+        creature::{
+            creature_plugin::{Activity, Monster, Player},
+            systems::stats::{BrainState, ConsciousnessStateEnum},
+        },
+        location::Location,
+        world::{clock::WorldClock, weather::RegionalWeather, world_plugin::Dormant},
+    },
+    AMBIENT_DAY_BRIGHTNESS, AMBIENT_NIGHT_BRIGHTNESS, CHUNK_SIZE, SUN_ILLUMINANCE,
+};
+
+/// Directional light moved by the daylight_system
+#[derive(Component)]
+pub struct Sun;
+
+/**
+ * Spawn the sun, its shadows cover the chunks around the player
+ */
+pub fn spawn_sun_system(mut commands: Commands) {
+    let half_size = CHUNK_SIZE * 2.;
+
+    commands
+        .spawn_bundle(DirectionalLightBundle {
+            directional_light: DirectionalLight {
+                illuminance: SUN_ILLUMINANCE,
+                shadows_enabled: true,
+                shadow_projection: OrthographicProjection {
+                    left: -half_size,
+                    right: half_size,
+                    bottom: -half_size,
+                    top: half_size,
+                    near: -10. * half_size,
+                    far: 10. * half_size,
+                    ..default()
+                },
+                ..default()
+            },
+            ..default()
+        })
+        .insert(Sun);
+}
+
+/**
+ * Advance the world clock with the frame time
+ */
+pub fn world_clock_system(time: Res<Time>, mut clock: ResMut<WorldClock>) {
+    clock.advance(time.delta_seconds());
+}
+
+/**
+ * Move the sun along the time of day, and dim the light at night and under bad weather
+ */
+pub fn daylight_system(
+    clock: Res<WorldClock>,
+    regional_weather: Res<RegionalWeather>,
+    mut ambient_light: ResMut<AmbientLight>,
+    mut sun_q: Query<(&mut Transform, &mut DirectionalLight), With<Sun>>,
+    player_q: Query<&Transform, (With<Player>, Without<Sun>)>,
+) {
+    let focus = player_q
+        .iter()
+        .next()
+        .map_or(Vec3::ZERO, |transform| transform.translation);
+    // Clouds and fog dim the light around the player
+    let daylight = clock.daylight() * regional_weather.at(focus).light_multiplier();
+
+    // The sun rises in the east (+x) and sets in the west, slightly tilted to the south
+    let angle = clock.sun_angle();
+    let toward_sun = Vec3::new(angle.cos(), angle.sin(), 0.3).normalize();
+
+    for (mut transform, mut light) in sun_q.iter_mut() {
+        *transform =
+            Transform::from_translation(focus + toward_sun * CHUNK_SIZE).looking_at(focus, Vec3::Y);
+        light.illuminance = SUN_ILLUMINANCE * daylight;
+    }
+
+    ambient_light.brightness =
+        AMBIENT_NIGHT_BRIGHTNESS + (AMBIENT_DAY_BRIGHTNESS - AMBIENT_NIGHT_BRIGHTNESS) * daylight;
+    ambient_light.color = Color::rgb(0.5, 0.55, 0.8) + Color::rgb(0.5, 0.45, 0.2) * daylight;
+}
+
+/**
+ * Put the idle monsters to sleep outside of their active period, and wake them up when it starts
+ */
+pub fn sleep_cycle_system(
+    clock: Res<WorldClock>,
+    mut monster_q: Query<
+        (&Activity, &Location, &mut BrainState),
+        (With<Monster>, Without<Dormant>),
+    >,
+) {
+    let period = clock.period();
+
+    for (activity, location, mut brain_state) in monster_q.iter_mut() {
+        let active = activity.is_active(period);
+
+        match brain_state.conscious {
+            ConsciousnessStateEnum::Awake if !active && location.destination.is_none() => {
+                brain_state.conscious = ConsciousnessStateEnum::Asleep;
+            }
+            ConsciousnessStateEnum::Asleep if active => {
+                brain_state.conscious = ConsciousnessStateEnum::Awake;
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spawn_monster(app: &mut App, activity: Activity) -> Entity {
+        app.world
+            .spawn()
+            .insert(Monster)
+            .insert(activity)
+            .insert(Location::default())
+            .insert(BrainState::new())
+            .id()
+    }
+
+    fn conscious(app: &App, entity: Entity) -> ConsciousnessStateEnum {
+        app.world
+            .get::<BrainState>(entity)
+            .unwrap()
+            .conscious
+            .clone()
+    }
+
+    #[test]
+    fn monsters_sleep_outside_of_their_active_period() {
+        let mut app = App::new();
+        app.insert_resource(WorldClock::new(100., 0.5))
+            .add_system(sleep_cycle_system);
+
+        let nocturnal = spawn_monster(&mut app, Activity::Nocturnal);
+        let diurnal = spawn_monster(&mut app, Activity::Diurnal);
+        let always = spawn_monster(&mut app, Activity::Always);
+
+        app.update();
+        assert_eq!(conscious(&app, nocturnal), ConsciousnessStateEnum::Asleep);
+        assert_eq!(conscious(&app, diurnal), ConsciousnessStateEnum::Awake);
+
+        // Advance the clock to the night
+        app.world.resource_mut::<WorldClock>().advance(50.);
+        app.update();
+        assert_eq!(conscious(&app, nocturnal), ConsciousnessStateEnum::Awake);
+        assert_eq!(conscious(&app, diurnal), ConsciousnessStateEnum::Asleep);
+        assert_eq!(conscious(&app, always), ConsciousnessStateEnum::Awake);
+    }
+}
@@ -0,0 +1,9 @@
+pub mod bounds;
+pub mod day_night;
+pub mod safe_zones;
+pub mod spawn_map;
+pub mod spawn_world;
+pub mod spawn_zones;
+pub mod streaming;
+pub mod weather;
+pub mod zones;
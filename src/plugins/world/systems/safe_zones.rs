@@ -0,0 +1,70 @@
+use bevy::prelude::*;
+
+use crate::{
+    plugins::{
+        creature::{creature_plugin::Player, systems::stats::Stats},
+        world::world_plugin::SafeZone,
+    },
+    MONSTER_STEP_DISTANCE, SAFE_ZONE_MARGIN, SAFE_ZONE_REGEN,
+};
+
+/// Is the position inside one of the safe zones
+pub fn in_safe_zone<'a>(
+    zones: impl IntoIterator<Item = (&'a GlobalTransform, &'a SafeZone)>,
+    position: Vec3,
+) -> bool {
+    zones.into_iter().any(|(transform, zone)| {
+        horizontal(position - transform.translation()).length() <= zone.radius
+    })
+}
+
+/// Velocity of a monster kept out of the safe zones
+///
+/// Monsters inside a zone walk straight out of it,
+/// monsters at its border cannot move further inside
+pub fn keep_out_of_safe_zones<'a>(
+    zones: impl IntoIterator<Item = (&'a GlobalTransform, &'a SafeZone)>,
+    position: Vec3,
+    linvel: Vec3,
+) -> Vec3 {
+    let mut linvel = linvel;
+
+    for (transform, zone) in zones {
+        let offset = horizontal(position - transform.translation());
+        let distance = offset.length();
+        if distance > zone.radius + SAFE_ZONE_MARGIN {
+            continue;
+        }
+
+        let outward = offset.normalize_or_zero();
+        if distance < zone.radius {
+            return outward * MONSTER_STEP_DISTANCE + Vec3::Y * linvel.y;
+        }
+
+        let inward = -linvel.dot(outward);
+        if inward > 0. {
+            linvel += outward * inward;
+        }
+    }
+
+    linvel
+}
+
+fn horizontal(vector: Vec3) -> Vec3 {
+    Vec3::new(vector.x, 0., vector.z)
+}
+
+/**
+ * Heal the players standing in a safe zone
+ */
+pub fn safe_zone_regen_system(
+    time: Res<Time>,
+    zone_q: Query<(&GlobalTransform, &SafeZone)>,
+    mut player_q: Query<(&Transform, &mut Stats), With<Player>>,
+) {
+    for (transform, mut stats) in player_q.iter_mut() {
+        if stats.hp > 0. && in_safe_zone(zone_q.iter(), transform.translation) {
+            stats.heal(SAFE_ZONE_REGEN * time.delta_seconds());
+        }
+    }
+}
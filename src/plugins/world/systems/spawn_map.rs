@@ -0,0 +1,327 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy_mod_raycast::RayCastMesh;
+use bevy_rapier3d::prelude::*;
+
+use crate::{
+    plugins::{
+        creature::{
+            creature_plugin::{Monster, Npc},
+            systems::stats::{CreatureName, Stats},
+        },
+        farming::systems::spawn_nodes::spawn_resource_node,
+        interaction::{
+            interactables::{Interactable, Locked},
+            systems::spawn_interactables::{spawn_chest, spawn_door, spawn_lever},
+        },
+        items::systems::{create_items::spawn_item, dropped_items::insert_dropped_item_bundle},
+        player::control::mouse::MouseRaycastSet,
+        world::{
+            generation::WorldGenerator,
+            map::MapFile,
+            map_plugin::{MapChunks, Maps, SpawnPoints},
+            systems::spawn_world::{spawn_monster, spawn_obstacle},
+            world_plugin::{CreatureSnapshot, SafeZone, SpawnZone, WorldAssets},
+            zone::{PlacementId, Portal, ZoneState},
+        },
+    },
+    GROUND_LEVEL, OVERWORLD_ZONE,
+};
+
+/// # spawn_maps_system
+/// Load the maps of the overworld and spawn them, see spawn_map
+///
+/// The chunks covered by the maps are registered in MapChunks so the world
+/// does not generate them, and kept flat so the terrain around meets the map ground.
+/// A map which cannot be loaded is skipped
+///
+pub fn spawn_maps_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut assets: ResMut<WorldAssets>,
+    maps: Res<Maps>,
+    mut map_chunks: ResMut<MapChunks>,
+    mut generator: ResMut<WorldGenerator>,
+    mut spawn_points: ResMut<SpawnPoints>,
+) {
+    for (index, name) in maps.0.iter().enumerate() {
+        let map = match MapFile::load(name) {
+            Ok(map) => map,
+            Err(error) => {
+                println!("Error while loading map {}: {}", name, error);
+                continue;
+            }
+        };
+
+        println!("Loading map {}", map.name);
+        map_chunks.0.extend(map.chunk_coords());
+        generator.flat_chunks.extend(map.chunk_coords());
+        spawn_points.register(OVERWORLD_ZONE, &map, Vec3::ZERO);
+
+        spawn_map(
+            &mut commands,
+            &asset_server,
+            &mut meshes,
+            &mut materials,
+            &mut assets,
+            map,
+            index,
+            Vec3::ZERO,
+            None,
+        );
+    }
+}
+
+/// # spawn_map
+/// Spawn the ground, colliders, props, resource nodes, spawn zones, safe zones, portals,
+/// chests, doors, levers, items and NPCs of a map, moved by the origin of its zone
+///
+/// The chests, doors, items and NPCs get the PlacementId of their placement in the map file.
+/// A zone entered again passes its ZoneState: the picked items and dead NPCs are not spawned
+/// again, and the chests stay opened and unlocked
+///
+pub fn spawn_map(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    assets: &mut ResMut<WorldAssets>,
+    map: MapFile,
+    map_index: usize,
+    origin: Vec3,
+    state: Option<&ZoneState>,
+) {
+    let placement = |index: usize| PlacementId {
+        map: map_index,
+        index,
+    };
+
+    // Position on the map ground, moved by the origin
+    let ground = |position: Vec2| origin + Vec3::new(position.x, GROUND_LEVEL, position.y);
+
+    for tile in map.ground.iter() {
+        let color = Color::rgb(tile.color[0], tile.color[1], tile.color[2]);
+
+        commands
+            .spawn_bundle(PbrBundle {
+                mesh: assets.mesh(meshes, Vec3::new(tile.size.x, 0.2, tile.size.y)),
+                material: assets.material(materials, color),
+                transform: Transform::from_translation(ground(tile.position) - Vec3::Y * 0.1),
+                ..default()
+            })
+            .insert(Collider::cuboid(tile.size.x / 2., 0.1, tile.size.y / 2.))
+            .insert(RayCastMesh::<MouseRaycastSet>::default());
+    }
+
+    for collider in map.colliders.iter() {
+        commands
+            .spawn_bundle(TransformBundle::from(
+                Transform::from_translation(
+                    ground(collider.position) + Vec3::Y * collider.size.y / 2.,
+                )
+                .with_rotation(Quat::from_rotation_y(collider.rotation)),
+            ))
+            .insert(Collider::cuboid(
+                collider.size.x / 2.,
+                collider.size.y / 2.,
+                collider.size.z / 2.,
+            ));
+    }
+
+    for prop in map.props.iter() {
+        let size = prop.obstacle.size();
+        spawn_obstacle(
+            commands,
+            assets.mesh(meshes, size),
+            assets.material(materials, prop.obstacle.color()),
+            prop.obstacle,
+            Transform::from_translation(ground(prop.position) + Vec3::Y * size.y / 2.)
+                .with_rotation(Quat::from_rotation_y(prop.rotation)),
+        );
+    }
+
+    for placement in map.resource_nodes.iter() {
+        spawn_resource_node(
+            commands,
+            assets.mesh(meshes, placement.node.size()),
+            assets.material(materials, placement.node.color()),
+            placement.node,
+            ground(placement.position),
+        );
+    }
+
+    for zone in map.spawn_zones.iter() {
+        commands
+            .spawn_bundle(TransformBundle::from(Transform::from_translation(ground(
+                zone.position,
+            ))))
+            .insert(SpawnZone::new(zone.radius, zone.monsters, zone.activity));
+    }
+
+    for zone in map.safe_zones.iter() {
+        commands
+            .spawn_bundle(TransformBundle::from(Transform::from_translation(ground(
+                zone.position,
+            ))))
+            .insert(SafeZone {
+                radius: zone.radius,
+            });
+    }
+
+    for portal in map.portals.into_iter() {
+        commands
+            .spawn_bundle(PbrBundle {
+                mesh: assets.mesh(
+                    meshes,
+                    Vec3::new(portal.radius * 2., 0.1, portal.radius * 2.),
+                ),
+                material: assets.material(materials, Color::rgb(0.5, 0.2, 0.8)),
+                transform: Transform::from_translation(ground(portal.position)),
+                ..default()
+            })
+            .insert(Portal {
+                radius: portal.radius,
+                zone: portal.zone,
+                spawn_point: portal.spawn_point,
+            });
+    }
+
+    for (index, chest) in map.chests.into_iter().enumerate() {
+        let id = placement(index);
+        let entity = spawn_chest(
+            commands,
+            assets,
+            meshes,
+            materials,
+            chest.loot,
+            state.is_some_and(|state| state.opened_chests.contains(&id)),
+            Transform::from_translation(ground(chest.position))
+                .with_rotation(Quat::from_rotation_y(chest.rotation)),
+        );
+        commands.entity(entity).insert(id);
+
+        if let Some(key) = chest.key {
+            if !state.is_some_and(|state| state.unlocked_chests.contains(&id)) {
+                commands.entity(entity).insert(Locked(key));
+            }
+        }
+    }
+
+    // Doors are spawned first, so the levers can be linked to them
+    let mut doors = HashMap::new();
+    for (index, door) in map.doors.into_iter().enumerate() {
+        let id = placement(index);
+        let entity = spawn_door(
+            commands,
+            assets,
+            meshes,
+            materials,
+            door.size,
+            door.open,
+            Transform::from_translation(ground(door.position))
+                .with_rotation(Quat::from_rotation_y(door.rotation)),
+        );
+        commands.entity(entity).insert(id);
+
+        if door.interactable {
+            commands.entity(entity).insert(Interactable);
+        }
+        if let Some(key) = door.key {
+            if !state.is_some_and(|state| state.unlocked_doors.contains(&id)) {
+                commands.entity(entity).insert(Locked(key));
+            }
+        }
+        if let Some(id) = door.id {
+            doors.insert(id, (entity, door.key.is_some()));
+        }
+    }
+
+    for lever in map.levers.into_iter() {
+        let targets = lever
+            .targets
+            .iter()
+            .filter_map(|id| match doors.get(id) {
+                Some((entity, false)) => Some(*entity),
+                Some((_, true)) => {
+                    println!(
+                        "Error while spawning lever of map {}: locked target {}",
+                        map.name, id
+                    );
+                    None
+                }
+                None => {
+                    println!(
+                        "Error while spawning lever of map {}: unknown target {}",
+                        map.name, id
+                    );
+                    None
+                }
+            })
+            .collect();
+
+        spawn_lever(
+            commands,
+            assets,
+            meshes,
+            materials,
+            targets,
+            ground(lever.position),
+        );
+    }
+
+    for (index, item_placement) in map.items.iter().enumerate() {
+        let id = placement(index);
+        let quantity = match state {
+            Some(state) => match state.items.get(&id) {
+                Some(quantity) => *quantity,
+                None => continue,
+            },
+            None => item_placement.quantity,
+        };
+
+        let item = spawn_item(commands, item_placement.item, quantity);
+        commands.entity(item).insert(id);
+
+        insert_dropped_item_bundle(
+            &mut commands.entity(item),
+            meshes,
+            materials,
+            item_placement.item,
+            Transform::from_translation(origin + item_placement.position),
+        );
+    }
+
+    for (index, npc) in map.npcs.into_iter().enumerate() {
+        let id = placement(index);
+        if !state.is_none_or(|state| state.npcs.contains(&id)) {
+            continue;
+        }
+
+        let entity = spawn_monster(
+            commands,
+            meshes,
+            materials,
+            asset_server,
+            CreatureSnapshot {
+                creature_type: npc.creature,
+                stats: Stats {
+                    atk: npc.atk.unwrap_or_else(|| npc.creature.attack()),
+                    hp: npc.hp,
+                    max_hp: npc.hp,
+                },
+                name: CreatureName(npc.name),
+                position: ground(npc.position) + Vec3::Y * npc.creature.size().y / 2.,
+                ranged_attack: npc.ranged_attack,
+                activity: npc.activity.unwrap_or_else(|| npc.creature.activity()),
+            },
+        );
+
+        commands.entity(entity).insert(Npc).insert(id);
+        if !npc.hostile.unwrap_or_else(|| npc.creature.hostile()) {
+            commands.entity(entity).remove::<Monster>();
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use bevy::{
+    prelude::*,
+    render::{mesh::Indices, render_resource::PrimitiveTopology},
+};
+use bevy_mod_raycast::RayCastMesh;
+use bevy_rapier3d::prelude::*;
+
+use crate::{
+    plugins::{
+        combat::ai::monster_ranged_attack::RangedAttack,
+        creature::{
+            creature_plugin::{CreatureConstructor, IsPlayer},
+            systems::physical::CreaturePhysicBundle,
+        },
+        farming::{
+            resource_nodes::{Depleted, ResourceNode},
+            systems::spawn_nodes::spawn_resource_node,
+        },
+        player::control::mouse::MouseRaycastSet,
+        world::{
+            generation::{ChunkCoord, ObstacleType, WorldGenerator},
+            world_plugin::{
+                Chunk, CreatureSnapshot, GeneratedNode, InChunk, Obstacle, ResourceNodeState,
+                SpawnZone, WorldAssets,
+            },
+        },
+    },
+    CHUNK_SIZE, GROUND_LEVEL, TERRAIN_RESOLUTION,
+};
+
+/// # spawn_chunk
+/// Spawn the ground, resource nodes, obstacles and spawn zones of a generated chunk
+///
+/// Resource nodes harvested before the chunk was unloaded get their stored state back,
+/// with the respawn timers already advanced by the time the chunk stayed unloaded
+///
+pub fn spawn_chunk(
+    commands: &mut Commands,
+    assets: &mut WorldAssets,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    generator: &WorldGenerator,
+    coord: ChunkCoord,
+    node_states: &[ResourceNodeState],
+) {
+    let layout = generator.chunk(coord);
+    let center = coord.center();
+    let (terrain_mesh, terrain_collider) = terrain(generator, coord);
+
+    commands
+        .spawn_bundle(PbrBundle {
+            mesh: meshes.add(terrain_mesh),
+            material: assets.material(materials, layout.biome.ground_color()),
+            transform: Transform::from_xyz(center.x, GROUND_LEVEL, center.z),
+            ..default()
+        })
+        .insert(terrain_collider)
+        .insert(RayCastMesh::<MouseRaycastSet>::default())
+        .insert(Chunk {
+            coord,
+            biome: layout.biome,
+        })
+        .insert(InChunk(coord));
+
+    for (index, (node_type, position)) in layout.resource_nodes.iter().enumerate() {
+        let node = spawn_resource_node(
+            commands,
+            assets.mesh(meshes, node_type.size()),
+            assets.material(materials, node_type.color()),
+            *node_type,
+            Vec3::new(position.x, generator.ground_height(*position), position.y),
+        );
+        commands
+            .entity(node)
+            .insert(InChunk(coord))
+            .insert(GeneratedNode(index));
+
+        let state = match node_states.iter().find(|state| state.index == index) {
+            Some(state) => state,
+            None => continue,
+        };
+
+        match state.respawn_in {
+            // Respawned while the chunk was unloaded
+            Some(respawn_in) if respawn_in <= 0. => {}
+            Some(respawn_in) => {
+                commands
+                    .entity(node)
+                    .remove::<Collider>()
+                    .insert(Visibility { is_visible: false })
+                    .insert(Depleted(Timer::from_seconds(respawn_in, false)));
+            }
+            None => {
+                commands.entity(node).insert(ResourceNode {
+                    node_type: *node_type,
+                    hp: state.hp,
+                });
+            }
+        }
+    }
+
+    for obstacle in layout.obstacles.iter() {
+        let size = obstacle.obstacle_type.size();
+        let obstacle_entity = spawn_obstacle(
+            commands,
+            assets.mesh(meshes, size),
+            assets.material(materials, obstacle.obstacle_type.color()),
+            obstacle.obstacle_type,
+            Transform::from_xyz(
+                obstacle.position.x,
+                generator.ground_height(obstacle.position) + size.y / 2.,
+                obstacle.position.y,
+            )
+            .with_rotation(Quat::from_rotation_y(obstacle.rotation)),
+        );
+        commands.entity(obstacle_entity).insert(InChunk(coord));
+    }
+
+    for zone in layout.spawn_zones.iter() {
+        commands
+            .spawn_bundle(TransformBundle::from(Transform::from_xyz(
+                zone.center.x,
+                generator.ground_height(zone.center),
+                zone.center.y,
+            )))
+            .insert(SpawnZone::new(zone.radius, zone.monsters, zone.activity))
+            .insert(InChunk(coord));
+    }
+}
+
+/// Mesh and heightfield collider of the terrain of a chunk, relative to its center at GROUND_LEVEL
+fn terrain(generator: &WorldGenerator, coord: ChunkCoord) -> (Mesh, Collider) {
+    let center = coord.center();
+    let size = TERRAIN_RESOLUTION + 1;
+    let step = CHUNK_SIZE / TERRAIN_RESOLUTION as f32;
+
+    let mut positions = Vec::new();
+    let mut normals = Vec::new();
+    let mut uvs = Vec::new();
+    // The heightfield rows go along the z axis and its columns along the x axis
+    let mut heights = vec![0.; size * size];
+
+    for row in 0..size {
+        for column in 0..size {
+            let local = Vec2::new(
+                column as f32 * step - CHUNK_SIZE / 2.,
+                row as f32 * step - CHUNK_SIZE / 2.,
+            );
+            let position = Vec2::new(center.x, center.z) + local;
+            let height = generator.ground_height(position) - GROUND_LEVEL;
+
+            heights[row + column * size] = height;
+            positions.push([local.x, height, local.y]);
+            normals.push(generator.ground_normal(position).to_array());
+            uvs.push([
+                column as f32 / TERRAIN_RESOLUTION as f32,
+                row as f32 / TERRAIN_RESOLUTION as f32,
+            ]);
+        }
+    }
+
+    let mut indices = Vec::new();
+    for row in 0..TERRAIN_RESOLUTION {
+        for column in 0..TERRAIN_RESOLUTION {
+            let index = (row * size + column) as u32;
+            let right = index + 1;
+            let below = index + size as u32;
+
+            // Counter clockwise seen from above
+            indices.extend([index, below, right, right, below, below + 1]);
+        }
+    }
+
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.set_indices(Some(Indices::U32(indices)));
+
+    let collider =
+        Collider::heightfield(heights, size, size, Vec3::new(CHUNK_SIZE, 1., CHUNK_SIZE));
+
+    (mesh, collider)
+}
+
+pub fn spawn_obstacle(
+    commands: &mut Commands,
+    mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+    obstacle_type: ObstacleType,
+    transform: Transform,
+) -> Entity {
+    let size = obstacle_type.size();
+
+    commands
+        .spawn_bundle(PbrBundle {
+            mesh,
+            material,
+            transform,
+            ..default()
+        })
+        .insert(Collider::cuboid(size.x / 2., size.y / 2., size.z / 2.))
+        .insert(Obstacle(obstacle_type))
+        .id()
+}
+
+/**
+ * Spawn a monster at the given position, e.g. a creature placed by a map or restored with its chunk
+ */
+pub fn spawn_monster(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    asset_server: &AssetServer,
+    creature: CreatureSnapshot,
+) -> Entity {
+    let monster = CreatureConstructor::new(
+        creature.creature_type,
+        creature.stats,
+        creature.name,
+        IsPlayer(false),
+        CreaturePhysicBundle::new(creature.creature_type, 0),
+    )
+    .init(commands, meshes, materials, asset_server);
+
+    commands
+        .entity(monster)
+        .insert(Transform::from_translation(creature.position));
+    commands.entity(monster).insert(creature.activity);
+    if let Some(projectile_type) = creature.ranged_attack {
+        commands
+            .entity(monster)
+            .insert(RangedAttack::new(projectile_type));
+    }
+
+    monster
+}
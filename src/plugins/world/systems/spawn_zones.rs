@@ -0,0 +1,85 @@
+use bevy::prelude::*;
+use rand::{thread_rng, Rng};
+
+use crate::plugins::{
+    combat::ai::monster_ranged_attack::RangedAttack,
+    creature::{
+        creature_plugin::{CreatureType, Monster},
+        systems::stats::{CreatureName, Stats},
+    },
+    world::{
+        clock::WorldClock,
+        generation::WorldGenerator,
+        systems::{safe_zones::in_safe_zone, spawn_world::spawn_monster},
+        world_plugin::{CreatureSnapshot, SafeZone, SpawnZone},
+    },
+};
+
+/**
+ * Spawn a monster in the spawn zones during their active period, until they are populated
+ *
+ * The monsters within twice the radius of a zone count as its population,
+ * a zone spawns at most one monster per cooldown
+ */
+pub fn spawn_zone_system(
+    time: Res<Time>,
+    clock: Res<WorldClock>,
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    generator: Res<WorldGenerator>,
+    mut zone_q: Query<(&GlobalTransform, &mut SpawnZone)>,
+    monster_q: Query<&Transform, With<Monster>>,
+    safe_zone_q: Query<(&GlobalTransform, &SafeZone)>,
+) {
+    let mut rng = thread_rng();
+    let period = clock.period();
+
+    for (transform, mut zone) in zone_q.iter_mut() {
+        if !zone.cooldown.tick(time.delta()).just_finished() || !zone.activity.is_active(period) {
+            continue;
+        }
+
+        let center = transform.translation();
+        let population = monster_q
+            .iter()
+            .filter(|monster| {
+                let offset = monster.translation - center;
+                Vec2::new(offset.x, offset.z).length() <= zone.radius * 2.
+            })
+            .count();
+        if population >= zone.monsters as usize {
+            continue;
+        }
+
+        let angle = rng.gen_range(0. ..std::f32::consts::TAU);
+        let distance = zone.radius * rng.gen::<f32>().sqrt();
+        let ground = Vec2::new(center.x, center.z) + Vec2::from_angle(angle) * distance;
+        let height = generator.ground_height(ground) + CreatureType::Monster.size().y / 2.;
+        let position = Vec3::new(ground.x, height, ground.y);
+
+        if in_safe_zone(safe_zone_q.iter(), position) {
+            continue;
+        }
+
+        spawn_monster(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            &asset_server,
+            CreatureSnapshot {
+                creature_type: CreatureType::Monster,
+                stats: Stats {
+                    atk: 1.,
+                    hp: 100.,
+                    max_hp: 100.,
+                },
+                name: CreatureName("Monstre".into()),
+                position,
+                ranged_attack: RangedAttack::roll(&mut rng),
+                activity: zone.activity,
+            },
+        );
+    }
+}
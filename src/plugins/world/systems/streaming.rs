@@ -0,0 +1,239 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::{RigidBody, Velocity};
+
+use crate::{
+    plugins::{
+        combat::ai::monster_ranged_attack::RangedAttack,
+        creature::{
+            creature_plugin::{Activity, CreatureType, Monster, Player},
+            systems::stats::{CreatureName, Stats},
+        },
+        farming::resource_nodes::{Depleted, ResourceNode},
+        world::{
+            generation::{ChunkCoord, WorldGenerator},
+            map_plugin::MapChunks,
+            systems::{
+                spawn_world::{spawn_chunk, spawn_monster},
+                weather::StormMonster,
+            },
+            world_plugin::{
+                ChunkState, ChunkStore, CreatureSnapshot, Dormant, GeneratedNode, InChunk,
+                LoadedChunks, ResourceNodeState, WorldAssets,
+            },
+        },
+    },
+    CHUNK_LOAD_RADIUS, CHUNK_UNLOAD_RADIUS, DORMANT_DISTANCE, SIMULATION_DISTANCE,
+};
+
+/// # chunk_streaming_system
+/// Load the chunks around the players and unload the ones far from every player
+///
+/// Chunks are loaded within CHUNK_LOAD_RADIUS and unloaded beyond CHUNK_UNLOAD_RADIUS,
+/// so a player walking along a chunk border does not reload the same chunks every frame.
+/// The chunks around the spawn are loaded while there is no player
+///
+/// Harvested resource nodes and the monsters of an unloaded chunk are stored
+/// in the ChunkStore, and restored when the chunk is loaded again.
+/// Storm monsters are not stored, they are despawned with the chunk
+///
+pub fn chunk_streaming_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    generator: Res<WorldGenerator>,
+    mut assets: ResMut<WorldAssets>,
+    mut loaded_chunks: ResMut<LoadedChunks>,
+    mut store: ResMut<ChunkStore>,
+    map_chunks: Res<MapChunks>,
+    player_q: Query<&Transform, With<Player>>,
+    chunk_entities_q: Query<(
+        Entity,
+        &InChunk,
+        Option<&GeneratedNode>,
+        Option<&ResourceNode>,
+        Option<&Depleted>,
+    )>,
+    monster_q: Query<
+        (
+            Entity,
+            &Transform,
+            &CreatureType,
+            &Stats,
+            &CreatureName,
+            &Activity,
+            Option<&RangedAttack>,
+            Option<&StormMonster>,
+        ),
+        With<Monster>,
+    >,
+) {
+    let now = time.seconds_since_startup();
+
+    let mut centers: Vec<ChunkCoord> = player_q
+        .iter()
+        .map(|transform| ChunkCoord::from_position(transform.translation))
+        .collect();
+    if centers.is_empty() {
+        centers.push(ChunkCoord::default());
+    }
+
+    // Unload the chunks far from every player
+    let unloaded: Vec<ChunkCoord> = loaded_chunks
+        .0
+        .iter()
+        .filter(|coord| {
+            centers
+                .iter()
+                .all(|center| coord.distance(*center) > CHUNK_UNLOAD_RADIUS)
+        })
+        .copied()
+        .collect();
+
+    for coord in unloaded {
+        let mut state = ChunkState {
+            unloaded_at: now,
+            resource_nodes: Vec::new(),
+            creatures: Vec::new(),
+        };
+
+        for (entity, in_chunk, generated, node, depleted) in chunk_entities_q.iter() {
+            if in_chunk.0 != coord {
+                continue;
+            }
+
+            if let (Some(generated), Some(node)) = (generated, node) {
+                let respawn_in = depleted.map(|depleted| {
+                    depleted.0.duration().as_secs_f32() - depleted.0.elapsed_secs()
+                });
+
+                if respawn_in.is_some() || node.hp < node.node_type.max_hp() {
+                    state.resource_nodes.push(ResourceNodeState {
+                        index: generated.0,
+                        hp: node.hp,
+                        respawn_in,
+                    });
+                }
+            }
+
+            commands.entity(entity).despawn_recursive();
+        }
+
+        for (
+            entity,
+            transform,
+            creature_type,
+            stats,
+            name,
+            activity,
+            ranged_attack,
+            storm_monster,
+        ) in monster_q.iter()
+        {
+            if ChunkCoord::from_position(transform.translation) != coord {
+                continue;
+            }
+
+            if storm_monster.is_none() {
+                state.creatures.push(CreatureSnapshot {
+                    creature_type: *creature_type,
+                    stats: *stats,
+                    name: name.clone(),
+                    position: transform.translation,
+                    ranged_attack: ranged_attack.map(|ranged_attack| ranged_attack.projectile_type),
+                    activity: *activity,
+                });
+            }
+            commands.entity(entity).despawn_recursive();
+        }
+
+        loaded_chunks.0.remove(&coord);
+        if !state.resource_nodes.is_empty() || !state.creatures.is_empty() {
+            store.0.insert(coord, state);
+        }
+    }
+
+    // Load the missing chunks around the players
+    for center in centers {
+        for coord in center.around(CHUNK_LOAD_RADIUS) {
+            if !generator.contains(coord) || loaded_chunks.0.contains(&coord) {
+                continue;
+            }
+
+            let (node_states, creatures) = match store.0.remove(&coord) {
+                Some(mut state) => {
+                    // The respawn timers kept running while the chunk was unloaded
+                    let unloaded_time = (now - state.unloaded_at) as f32;
+                    for node in state.resource_nodes.iter_mut() {
+                        node.respawn_in =
+                            node.respawn_in.map(|respawn_in| respawn_in - unloaded_time);
+                    }
+
+                    (state.resource_nodes, state.creatures)
+                }
+                None => (Vec::new(), Vec::new()),
+            };
+
+            // Chunks covered by a map keep the map content, only their creatures are restored
+            if !map_chunks.0.contains(&coord) {
+                spawn_chunk(
+                    &mut commands,
+                    &mut assets,
+                    &mut meshes,
+                    &mut materials,
+                    &generator,
+                    coord,
+                    &node_states,
+                );
+            }
+
+            for creature in creatures {
+                spawn_monster(
+                    &mut commands,
+                    &mut meshes,
+                    &mut materials,
+                    &asset_server,
+                    creature,
+                );
+            }
+
+            loaded_chunks.0.insert(coord);
+        }
+    }
+}
+
+/**
+ * Freeze the monsters far from every player and wake them up when a player comes closer,
+ * dormant monsters are skipped by the AI and movement systems and their body stops simulating
+ */
+pub fn simulation_lod_system(
+    mut commands: Commands,
+    player_q: Query<&Transform, With<Player>>,
+    mut monster_q: Query<
+        (
+            Entity,
+            &Transform,
+            &mut RigidBody,
+            &mut Velocity,
+            Option<&Dormant>,
+        ),
+        (With<Monster>, Without<Player>),
+    >,
+) {
+    for (entity, transform, mut rigid_body, mut velocity, dormant) in monster_q.iter_mut() {
+        let distance = player_q
+            .iter()
+            .map(|player| player.translation.distance(transform.translation))
+            .fold(f32::INFINITY, f32::min);
+
+        if dormant.is_none() && distance > DORMANT_DISTANCE {
+            *rigid_body = RigidBody::Fixed;
+            velocity.linvel = Vec3::splat(0.);
+            commands.entity(entity).insert(Dormant);
+        } else if dormant.is_some() && distance <= SIMULATION_DISTANCE {
+            *rigid_body = RigidBody::Dynamic;
+            commands.entity(entity).remove::<Dormant>();
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+use rand::{thread_rng, Rng};
+
+use crate::{
+    plugins::{
+        creature::{
+            creature_plugin::{Activity, Creature, CreatureType, Player},
+            systems::{
+                stats::{CreatureName, Stats},
+                status_effects::{StatusEffectType, StatusEffects},
+            },
+        },
+        world::{
+            generation::{ChunkCoord, WorldGenerator},
+            systems::{safe_zones::in_safe_zone, spawn_world::spawn_monster},
+            weather::{RegionWeather, RegionalWeather, Weather},
+            world_events::WeatherChangedEvent,
+            world_plugin::{CreatureSnapshot, Dormant, LoadedChunks, SafeZone},
+        },
+    },
+    RAIN_BURN_IMMUNITY, STORM_MONSTERS, STORM_SPAWN_DISTANCE,
+};
+
+/// Monster brought by a storm, it leaves with the storm of its region
+#[derive(Component)]
+pub struct StormMonster(pub ChunkCoord);
+
+/**
+ * Change the weather of the loaded regions when it ends
+ */
+pub fn weather_transition_system(
+    time: Res<Time>,
+    loaded_chunks: Res<LoadedChunks>,
+    mut regional_weather: ResMut<RegionalWeather>,
+    mut ev_weather_changed: EventWriter<WeatherChangedEvent>,
+) {
+    let mut rng = thread_rng();
+    let regions: HashSet<ChunkCoord> = loaded_chunks.0.iter().map(|coord| coord.region()).collect();
+
+    for region in regions {
+        let region_weather = regional_weather.0.entry(region).or_insert_with(|| {
+            ev_weather_changed.send(WeatherChangedEvent(region, Weather::Clear));
+            RegionWeather::new(Weather::Clear, &mut rng)
+        });
+
+        if !region_weather.timer.tick(time.delta()).finished() {
+            continue;
+        }
+
+        let weather = region_weather.weather.next(&mut rng);
+        *region_weather = RegionWeather::new(weather, &mut rng);
+        ev_weather_changed.send(WeatherChangedEvent(region, weather));
+    }
+}
+
+/**
+ * Put out the burning creatures under the rain
+ */
+pub fn weather_effects_system(
+    regional_weather: Res<RegionalWeather>,
+    mut creature_q: Query<(&Transform, &mut StatusEffects), (With<Creature>, Without<Dormant>)>,
+) {
+    for (transform, mut status_effects) in creature_q.iter_mut() {
+        if regional_weather.at(transform.translation).is_wet()
+            && status_effects.has(StatusEffectType::Burn)
+        {
+            status_effects.cure(StatusEffectType::Burn, RAIN_BURN_IMMUNITY);
+        }
+    }
+}
+
+/**
+ * Spawn storm monsters around the players caught in a storm
+ *
+ * Each region spawns at most one monster per cooldown, up to STORM_MONSTERS at the same time
+ */
+pub fn storm_spawn_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    generator: Res<WorldGenerator>,
+    mut regional_weather: ResMut<RegionalWeather>,
+    player_q: Query<&Transform, With<Player>>,
+    storm_monster_q: Query<&StormMonster>,
+    safe_zone_q: Query<(&GlobalTransform, &SafeZone)>,
+) {
+    let mut rng = thread_rng();
+
+    for (region, region_weather) in regional_weather.0.iter_mut() {
+        if region_weather.weather != Weather::Storm
+            || !region_weather
+                .storm_spawn
+                .tick(time.delta())
+                .just_finished()
+        {
+            continue;
+        }
+
+        let player = match player_q
+            .iter()
+            .find(|player| ChunkCoord::from_position(player.translation).region() == *region)
+        {
+            Some(player) => player.translation,
+            None => continue,
+        };
+
+        let storm_monsters = storm_monster_q
+            .iter()
+            .filter(|storm_monster| storm_monster.0 == *region)
+            .count();
+        if storm_monsters >= STORM_MONSTERS {
+            continue;
+        }
+
+        let angle = rng.gen_range(0. ..std::f32::consts::TAU);
+        let direction = Vec3::new(angle.cos(), 0., angle.sin());
+        let ground = generator.clamp(player + direction * STORM_SPAWN_DISTANCE);
+        let height = generator.ground_height(Vec2::new(ground.x, ground.z))
+            + CreatureType::Monster.size().y / 2.;
+        let position = Vec3::new(ground.x, height, ground.z);
+
+        if in_safe_zone(safe_zone_q.iter(), position) {
+            continue;
+        }
+
+        let monster = spawn_monster(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            &asset_server,
+            CreatureSnapshot {
+                creature_type: CreatureType::Monster,
+                stats: Stats {
+                    atk: 2.,
+                    hp: 150.,
+                    max_hp: 150.,
+                },
+                name: CreatureName("Monstre de l'orage".into()),
+                position,
+                ranged_attack: None,
+                // Storm monsters hunt whatever the time of day
+                activity: Activity::Always,
+            },
+        );
+        commands.entity(monster).insert(StormMonster(*region));
+    }
+}
+
+/**
+ * Despawn the storm monsters of the regions where the storm ended
+ */
+pub fn storm_end_system(
+    mut commands: Commands,
+    mut ev_weather_changed: EventReader<WeatherChangedEvent>,
+    storm_monster_q: Query<(Entity, &StormMonster)>,
+) {
+    for WeatherChangedEvent(region, weather) in ev_weather_changed.iter() {
+        if *weather == Weather::Storm {
+            continue;
+        }
+
+        for (entity, storm_monster) in storm_monster_q.iter() {
+            if storm_monster.0 == *region {
+                commands.entity(entity).despawn_recursive();
+            }
+        }
+    }
+}
@@ -0,0 +1,270 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::Velocity;
+
+use crate::{
+    plugins::{
+        creature::creature_plugin::{CreatureType, Npc, Player},
+        interaction::interactables::{Chest, Door, Locked},
+        items::items_plugin::{Item, Quantity},
+        location::Location,
+        world::{
+            generation::{ChunkCoord, WorldGenerator},
+            map_plugin::{MapChunks, SpawnPoints},
+            systems::{bounds::spawn_walls, day_night::Sun, spawn_map::spawn_map},
+            world_events::EnterZoneEvent,
+            world_plugin::WorldAssets,
+            zone::{
+                LoadedZones, PlacementId, Portal, PortalCooldown, ZoneFile, ZoneState, ZoneStore,
+                ZoneWall, Zones,
+            },
+        },
+    },
+    OVERWORLD_ZONE, PORTAL_COOLDOWN, ZONES,
+};
+
+/**
+ * Load the zone files, a zone which cannot be loaded is skipped
+ */
+pub fn load_zone_files_system(mut zones: ResMut<Zones>) {
+    for name in ZONES {
+        match ZoneFile::load(name) {
+            Ok(zone) => {
+                zones.0.insert(name.to_string(), zone);
+            }
+            Err(error) => println!("Error while loading zone {}: {}", name, error),
+        }
+    }
+}
+
+/**
+ * Send the players walking into a portal to its zone
+ */
+pub fn portal_system(
+    mut commands: Commands,
+    mut ev_enter_zone: EventWriter<EnterZoneEvent>,
+    portal_q: Query<(&GlobalTransform, &Portal)>,
+    player_q: Query<(Entity, &Transform), (With<Player>, Without<PortalCooldown>)>,
+) {
+    for (player, transform) in player_q.iter() {
+        let portal = portal_q.iter().find(|(portal_transform, portal)| {
+            let offset = transform.translation - portal_transform.translation();
+            Vec2::new(offset.x, offset.z).length() <= portal.radius
+        });
+
+        if let Some((_, portal)) = portal {
+            ev_enter_zone.send(EnterZoneEvent(
+                player,
+                portal.zone.clone(),
+                portal.spawn_point.clone(),
+            ));
+            commands
+                .entity(player)
+                .insert(PortalCooldown(Timer::from_seconds(PORTAL_COOLDOWN, false)));
+        }
+    }
+}
+
+pub fn portal_cooldown_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut player_q: Query<(Entity, &mut PortalCooldown)>,
+) {
+    for (entity, mut cooldown) in player_q.iter_mut() {
+        if cooldown.0.tick(time.delta()).finished() {
+            commands.entity(entity).remove::<PortalCooldown>();
+        }
+    }
+}
+
+/// # enter_zone_system
+/// Move the players to the spawn point of their target zone
+///
+/// A zone which is not loaded spawns its maps around its origin and its walls,
+/// restoring the state it was left in, the overworld is always loaded.
+/// The zone the player leaves is unloaded by the unload_zones_system once no player is left inside
+///
+pub fn enter_zone_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut assets: ResMut<WorldAssets>,
+    mut generator: ResMut<WorldGenerator>,
+    mut map_chunks: ResMut<MapChunks>,
+    mut spawn_points: ResMut<SpawnPoints>,
+    mut loaded_zones: ResMut<LoadedZones>,
+    mut zone_store: ResMut<ZoneStore>,
+    zones: Res<Zones>,
+    mut ev_enter_zone: EventReader<EnterZoneEvent>,
+    mut player_q: Query<
+        (&mut Transform, &mut Velocity, &mut Location, &CreatureType),
+        With<Player>,
+    >,
+) {
+    for EnterZoneEvent(player, zone_name, spawn_point) in ev_enter_zone.iter() {
+        if zone_name != OVERWORLD_ZONE && !loaded_zones.0.contains(zone_name) {
+            let zone = match zones.0.get(zone_name) {
+                Some(zone) => zone,
+                None => {
+                    println!("Error while entering zone {}: unknown zone", zone_name);
+                    continue;
+                }
+            };
+
+            println!("Loading zone {}", zone.name);
+            let origin = zone.origin();
+            let state = zone_store.0.remove(zone_name);
+            for (index, map) in zone.load_maps().into_iter().enumerate() {
+                let chunks = map
+                    .chunk_coords()
+                    .map(|coord| ChunkCoord::new(coord.x + zone.origin.0, coord.z + zone.origin.1))
+                    .collect::<Vec<ChunkCoord>>();
+                map_chunks.0.extend(chunks.iter().copied());
+                generator.flat_chunks.extend(chunks);
+                spawn_points.register(zone_name, &map, origin);
+
+                spawn_map(
+                    &mut commands,
+                    &asset_server,
+                    &mut meshes,
+                    &mut materials,
+                    &mut assets,
+                    map,
+                    index,
+                    origin,
+                    state.as_ref(),
+                );
+            }
+
+            for wall in spawn_walls(&mut commands, origin, zone.half_size) {
+                commands.entity(wall).insert(ZoneWall(zone_name.clone()));
+            }
+
+            loaded_zones.0.insert(zone_name.clone());
+        }
+
+        let position = match spawn_points.get(zone_name, spawn_point) {
+            Some(position) => position,
+            None => {
+                println!(
+                    "Error while entering zone {}: unknown spawn point {}",
+                    zone_name, spawn_point
+                );
+                continue;
+            }
+        };
+
+        let (mut transform, mut velocity, mut location, creature_type) =
+            match player_q.get_mut(*player) {
+                Ok(player) => player,
+                Err(error) => {
+                    println!("Error while entering zone {}: {}", zone_name, error);
+                    continue;
+                }
+            };
+
+        transform.translation = position + Vec3::Y * creature_type.size().y / 2.;
+        velocity.linvel = Vec3::ZERO;
+        location.position = Some(transform.translation);
+        location.destination = None;
+    }
+}
+
+/// # unload_zones_system
+/// Despawn the zones left by every player, with everything inside them
+///
+/// The items left on the ground, the living NPCs and the opened and unlocked chests and doors
+/// are stored in the ZoneStore, the zone is spawned again from its files and this state
+/// the next time a player enters it. The cameras, lights and UI nodes are kept
+///
+pub fn unload_zones_system(
+    mut commands: Commands,
+    zones: Res<Zones>,
+    mut loaded_zones: ResMut<LoadedZones>,
+    mut zone_store: ResMut<ZoneStore>,
+    mut spawn_points: ResMut<SpawnPoints>,
+    player_q: Query<&Transform, With<Player>>,
+    placement_q: Query<(
+        &PlacementId,
+        &GlobalTransform,
+        Option<&Item>,
+        Option<&Quantity>,
+        Option<&Npc>,
+        Option<&Chest>,
+        Option<&Door>,
+        Option<&Locked>,
+    )>,
+    entity_q: Query<
+        (Entity, &GlobalTransform),
+        (
+            Without<Parent>,
+            Without<Player>,
+            Without<Camera>,
+            Without<Sun>,
+            Without<Node>,
+            Without<ZoneWall>,
+        ),
+    >,
+    wall_q: Query<(Entity, &ZoneWall)>,
+) {
+    let left_zones: Vec<String> = loaded_zones
+        .0
+        .iter()
+        .filter(|name| match zones.0.get(*name) {
+            Some(zone) => !player_q
+                .iter()
+                .any(|transform| zone.contains(transform.translation)),
+            None => true,
+        })
+        .cloned()
+        .collect();
+
+    for name in left_zones {
+        println!("Unloading zone {}", name);
+
+        if let Some(zone) = zones.0.get(&name) {
+            let mut state = ZoneState::default();
+            for (id, transform, item, quantity, npc, chest, door, locked) in placement_q.iter() {
+                if !zone.contains(transform.translation()) {
+                    continue;
+                }
+
+                if item.is_some() {
+                    state
+                        .items
+                        .insert(*id, quantity.map_or(1, |quantity| quantity.0));
+                }
+                if npc.is_some() {
+                    state.npcs.insert(*id);
+                }
+                if let Some(chest) = chest {
+                    if chest.opened {
+                        state.opened_chests.insert(*id);
+                    }
+                    if locked.is_none() {
+                        state.unlocked_chests.insert(*id);
+                    }
+                }
+                if door.is_some() && locked.is_none() {
+                    state.unlocked_doors.insert(*id);
+                }
+            }
+            zone_store.0.insert(name.clone(), state);
+
+            for (entity, transform) in entity_q.iter() {
+                if zone.contains(transform.translation()) {
+                    commands.entity(entity).despawn_recursive();
+                }
+            }
+        }
+
+        for (entity, wall) in wall_q.iter() {
+            if wall.0 == name {
+                commands.entity(entity).despawn_recursive();
+            }
+        }
+
+        spawn_points.remove_zone(&name);
+        loaded_zones.0.remove(&name);
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::{
+    FOG_SIGHT_FACTOR, RAIN_SPEED_FACTOR, STORM_SIGHT_FACTOR, STORM_SPAWN_COOLDOWN,
+    STORM_SPEED_FACTOR, WEATHER_MAX_DURATION, WEATHER_MIN_DURATION,
+};
+
+use super::generation::ChunkCoord;
+
+// ------------------
+//
+// Weather
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Weather {
+    #[default]
+    Clear,
+    Rain,
+    Fog,
+    Storm,
+}
+
+impl Weather {
+    pub const ALL: [Weather; 4] = [Weather::Clear, Weather::Rain, Weather::Fog, Weather::Storm];
+
+    /// Chances to turn into the next weather when this one ends, storms only come after rain
+    pub fn transition_weight(&self, next: Weather) -> u32 {
+        match (self, next) {
+            (Weather::Clear, Weather::Clear) => 3,
+            (Weather::Clear, Weather::Rain) => 3,
+            (Weather::Clear, Weather::Fog) => 2,
+            (Weather::Rain, Weather::Clear) => 3,
+            (Weather::Rain, Weather::Rain) => 1,
+            (Weather::Rain, Weather::Fog) => 1,
+            (Weather::Rain, Weather::Storm) => 2,
+            (Weather::Fog, Weather::Clear) => 3,
+            (Weather::Fog, Weather::Rain) => 1,
+            (Weather::Fog, Weather::Fog) => 1,
+            (Weather::Storm, Weather::Clear) => 1,
+            (Weather::Storm, Weather::Rain) => 3,
+            _ => 0,
+        }
+    }
+
+    pub fn next(&self, rng: &mut impl Rng) -> Weather {
+        let total: u32 = Weather::ALL
+            .iter()
+            .map(|next| self.transition_weight(*next))
+            .sum();
+        let mut roll = rng.gen_range(0..total);
+
+        for next in Weather::ALL {
+            if roll < self.transition_weight(next) {
+                return next;
+            }
+            roll -= self.transition_weight(next);
+        }
+
+        *self
+    }
+
+    /// Multiplier of the distance from which the monsters notice the players
+    pub fn sight_multiplier(&self) -> f32 {
+        match self {
+            Weather::Fog => FOG_SIGHT_FACTOR,
+            Weather::Storm => STORM_SIGHT_FACTOR,
+            _ => 1.,
+        }
+    }
+
+    /// Multiplier of the walking speed of the creatures
+    pub fn speed_multiplier(&self) -> f32 {
+        match self {
+            Weather::Rain => RAIN_SPEED_FACTOR,
+            Weather::Storm => STORM_SPEED_FACTOR,
+            _ => 1.,
+        }
+    }
+
+    /// Wet weathers put out the burning creatures
+    pub fn is_wet(&self) -> bool {
+        matches!(self, Weather::Rain | Weather::Storm)
+    }
+
+    /// Multiplier of the sunlight, for the clients
+    pub fn light_multiplier(&self) -> f32 {
+        match self {
+            Weather::Clear => 1.,
+            Weather::Rain => 0.5,
+            Weather::Fog => 0.6,
+            Weather::Storm => 0.25,
+        }
+    }
+}
+
+/// Weather of a region and the time left before it changes
+pub struct RegionWeather {
+    pub weather: Weather,
+    pub timer: Timer,
+    /// Time between two storm monsters spawned in the region
+    pub storm_spawn: Timer,
+}
+
+impl RegionWeather {
+    pub fn new(weather: Weather, rng: &mut impl Rng) -> Self {
+        Self {
+            weather,
+            timer: Timer::from_seconds(
+                rng.gen_range(WEATHER_MIN_DURATION..=WEATHER_MAX_DURATION),
+                false,
+            ),
+            storm_spawn: Timer::from_seconds(STORM_SPAWN_COOLDOWN, true),
+        }
+    }
+}
+
+/// # RegionalWeather
+/// Weather of the regions of the world, see ChunkCoord::region
+///
+/// A region starts clear when one of its chunks is loaded for the first time,
+/// its weather only changes while one of its chunks is loaded
+///
+#[derive(Default)]
+pub struct RegionalWeather(pub HashMap<ChunkCoord, RegionWeather>);
+
+impl RegionalWeather {
+    /// Weather at a world position, clear in the regions never loaded
+    pub fn at(&self, position: Vec3) -> Weather {
+        self.0
+            .get(&ChunkCoord::from_position(position).region())
+            .map_or(Weather::Clear, |region| region.weather)
+    }
+}
@@ -0,0 +1,14 @@
+use bevy::prelude::Entity;
+
+use super::{generation::ChunkCoord, weather::Weather};
+
+/// The weather of a region changed, for the clients to update the rain, fog and lighting
+/// ### Param1 - {ChunkCoord} - The region, see ChunkCoord::region
+/// ### Param2 - {Weather} - The new weather of the region
+pub struct WeatherChangedEvent(pub ChunkCoord, pub Weather);
+
+/// Move a player to a spawn point of a zone, loading the zone if needed
+/// ### Param1 - {Entity} - The player
+/// ### Param2 - {String} - The name of the target zone
+/// ### Param3 - {String} - The name of the spawn point in the target zone
+pub struct EnterZoneEvent(pub Entity, pub String, pub String);
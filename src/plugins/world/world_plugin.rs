@@ -0,0 +1,186 @@
+use std::collections::{HashMap, HashSet};
+
+use bevy::prelude::*;
+
+use crate::{
+    plugins::{
+        combat::weapons::range::projectile::ProjectileType,
+        creature::{
+            creature_plugin::{Activity, CreatureType},
+            systems::stats::{CreatureName, Stats},
+        },
+    },
+    SPAWN_ZONE_COOLDOWN, WORLD_SEED,
+};
+
+use super::{
+    clock::WorldClock,
+    generation::{Biome, ChunkCoord, ObstacleType, WorldGenerator},
+    systems::{
+        bounds::{keep_in_bounds_system, spawn_world_walls_system},
+        day_night::{daylight_system, sleep_cycle_system, spawn_sun_system, world_clock_system},
+        safe_zones::safe_zone_regen_system,
+        spawn_zones::spawn_zone_system,
+        streaming::{chunk_streaming_system, simulation_lod_system},
+        weather::{
+            storm_end_system, storm_spawn_system, weather_effects_system, weather_transition_system,
+        },
+    },
+    weather::RegionalWeather,
+    world_events::WeatherChangedEvent,
+};
+
+pub struct WorldPlugin;
+impl Plugin for WorldPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WorldSeed>()
+            .init_resource::<WorldGenerator>()
+            .init_resource::<WorldAssets>()
+            .init_resource::<LoadedChunks>()
+            .init_resource::<ChunkStore>()
+            .init_resource::<WorldClock>()
+            .init_resource::<RegionalWeather>()
+            .add_event::<WeatherChangedEvent>()
+            // Load the chunks around the spawn before the first frame
+            .add_startup_system(chunk_streaming_system)
+            .add_system(chunk_streaming_system)
+            .add_startup_system(spawn_world_walls_system)
+            .add_system(simulation_lod_system)
+            .add_system(keep_in_bounds_system)
+            .add_system(safe_zone_regen_system)
+            .add_startup_system(spawn_sun_system)
+            .add_system(world_clock_system)
+            .add_system(daylight_system)
+            .add_system(sleep_cycle_system)
+            .add_system(spawn_zone_system)
+            .add_system(weather_transition_system)
+            .add_system(weather_effects_system)
+            .add_system(storm_spawn_system)
+            .add_system(storm_end_system);
+    }
+}
+
+/// Seed of the world generator, the same seed always produces the same world
+pub struct WorldSeed(pub u64);
+
+impl Default for WorldSeed {
+    fn default() -> Self {
+        Self(WORLD_SEED)
+    }
+}
+
+impl FromWorld for WorldGenerator {
+    fn from_world(world: &mut World) -> Self {
+        WorldGenerator::new(world.resource::<WorldSeed>().0)
+    }
+}
+
+/// Meshes and materials shared by every chunk, by size and color
+#[derive(Default)]
+pub struct WorldAssets {
+    meshes: HashMap<[u32; 3], Handle<Mesh>>,
+    materials: HashMap<[u32; 4], Handle<StandardMaterial>>,
+}
+
+impl WorldAssets {
+    pub fn mesh(&mut self, meshes: &mut Assets<Mesh>, size: Vec3) -> Handle<Mesh> {
+        self.meshes
+            .entry(size.to_array().map(f32::to_bits))
+            .or_insert_with(|| meshes.add(Mesh::from(shape::Box::new(size.x, size.y, size.z))))
+            .clone()
+    }
+
+    pub fn material(
+        &mut self,
+        materials: &mut Assets<StandardMaterial>,
+        color: Color,
+    ) -> Handle<StandardMaterial> {
+        self.materials
+            .entry(color.as_rgba_f32().map(f32::to_bits))
+            .or_insert_with(|| materials.add(color.into()))
+            .clone()
+    }
+}
+
+/// Chunks currently spawned in the world
+#[derive(Default)]
+pub struct LoadedChunks(pub HashSet<ChunkCoord>);
+
+/// State of the unloaded chunks which changed since their generation,
+/// restored when the chunk is loaded again
+#[derive(Default)]
+pub struct ChunkStore(pub HashMap<ChunkCoord, ChunkState>);
+
+pub struct ChunkState {
+    /// Seconds since startup when the chunk was unloaded
+    pub unloaded_at: f64,
+    pub resource_nodes: Vec<ResourceNodeState>,
+    pub creatures: Vec<CreatureSnapshot>,
+}
+
+/// Harvested resource node, identified by its index in the chunk layout
+pub struct ResourceNodeState {
+    pub index: usize,
+    pub hp: f32,
+    /// Seconds left before the node respawns, None if it is not depleted
+    pub respawn_in: Option<f32>,
+}
+
+/// Creature kept outside of the world, e.g. stored with its unloaded chunk or placed by a map,
+/// and spawned with spawn_monster
+pub struct CreatureSnapshot {
+    pub creature_type: CreatureType,
+    pub stats: Stats,
+    pub name: CreatureName,
+    pub position: Vec3,
+    pub ranged_attack: Option<ProjectileType>,
+    pub activity: Activity,
+}
+
+/// Ground of a chunk of the world
+#[derive(Component)]
+pub struct Chunk {
+    pub coord: ChunkCoord,
+    pub biome: Biome,
+}
+
+/// Entity spawned with a chunk and despawned with it
+#[derive(Component)]
+pub struct InChunk(pub ChunkCoord);
+
+/// Index of a resource node in the layout of its chunk
+#[derive(Component)]
+pub struct GeneratedNode(pub usize);
+
+#[derive(Component)]
+pub struct Obstacle(pub ObstacleType);
+
+/// Area where monsters spawn during their active period, until it holds enough of them
+#[derive(Component)]
+pub struct SpawnZone {
+    pub radius: f32,
+    pub monsters: u32,
+    pub activity: Activity,
+    pub cooldown: Timer,
+}
+
+impl SpawnZone {
+    pub fn new(radius: f32, monsters: u32, activity: Activity) -> Self {
+        Self {
+            radius,
+            monsters,
+            activity,
+            cooldown: Timer::from_seconds(SPAWN_ZONE_COOLDOWN, true),
+        }
+    }
+}
+
+/// Horizontal disc where monsters cannot enter nor aggro, and players regenerate
+#[derive(Component)]
+pub struct SafeZone {
+    pub radius: f32,
+}
+
+/// Creature too far from the players to be simulated, frozen until a player comes closer
+#[derive(Component)]
+pub struct Dormant;
@@ -0,0 +1,137 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+};
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::{
+    utils::{assets::asset_path, error::ErrorMessage},
+    ZONES_FOLDER, ZONE_BOUNDS_MARGIN,
+};
+
+use super::{generation::ChunkCoord, map::MapFile};
+
+/// # ZoneFile
+/// Area apart from the overworld, e.g. a dungeon or an interior, loaded from `assets/zones/<name>.ron`
+///
+/// The zone is centered on its origin chunk, placed away from the overworld borders,
+/// and closed by invisible walls at `half_size` from its center.
+/// Its maps are spawned around the origin when a player enters the zone,
+/// and despawned with everything inside it once the last player leaves.
+/// What the players changed in the zone is kept in the ZoneStore
+///
+#[derive(Debug, Deserialize)]
+pub struct ZoneFile {
+    pub name: String,
+    pub origin: (i32, i32),
+    pub half_size: Vec2,
+    pub maps: Vec<String>,
+}
+
+impl ZoneFile {
+    pub fn load(name: &str) -> Result<Self, ErrorMessage> {
+        let content = fs::read_to_string(asset_path(ZONES_FOLDER, name))?;
+        Ok(ron::from_str(&content)?)
+    }
+
+    /// World position of the zone center, on the ground plane
+    pub fn origin(&self) -> Vec3 {
+        ChunkCoord::new(self.origin.0, self.origin.1).center()
+    }
+
+    /// Is the position inside the zone, or close enough to be brought back in
+    pub fn contains(&self, position: Vec3) -> bool {
+        let offset = position - self.origin();
+
+        offset.x.abs() <= self.half_size.x + ZONE_BOUNDS_MARGIN
+            && offset.z.abs() <= self.half_size.y + ZONE_BOUNDS_MARGIN
+    }
+
+    /// Position moved back inside the zone walls, the height is kept
+    pub fn clamp(&self, position: Vec3) -> Vec3 {
+        let origin = self.origin();
+
+        Vec3::new(
+            position
+                .x
+                .clamp(origin.x - self.half_size.x, origin.x + self.half_size.x),
+            position.y,
+            position
+                .z
+                .clamp(origin.z - self.half_size.y, origin.z + self.half_size.y),
+        )
+    }
+
+    /// Load the maps of the zone, the maps which cannot be loaded are skipped
+    pub fn load_maps(&self) -> Vec<MapFile> {
+        self.maps
+            .iter()
+            .filter_map(|name| match MapFile::load(name) {
+                Ok(map) => Some(map),
+                Err(error) => {
+                    println!("Error while loading map {}: {}", name, error);
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+/// Zones apart from the overworld, by name
+#[derive(Default)]
+pub struct Zones(pub HashMap<String, ZoneFile>);
+
+impl Zones {
+    /// Zone containing the position, None in the overworld
+    pub fn at(&self, position: Vec3) -> Option<&ZoneFile> {
+        self.0.values().find(|zone| zone.contains(position))
+    }
+}
+
+/// Names of the zones spawned for the players inside them
+#[derive(Default)]
+pub struct LoadedZones(pub HashSet<String>);
+
+/// Placement a map entity was spawned from, by index of the map in its zone
+/// and of the placement in its list
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PlacementId {
+    pub map: usize,
+    pub index: usize,
+}
+
+/// What the players changed in a zone, so leaving and entering it again does not reset it.
+/// Doors and levers are spawned in their initial position
+#[derive(Default)]
+pub struct ZoneState {
+    /// Quantities of the placed items still on the ground
+    pub items: HashMap<PlacementId, u32>,
+    /// Placed NPCs still alive
+    pub npcs: HashSet<PlacementId>,
+    pub opened_chests: HashSet<PlacementId>,
+    /// Locked chests and doors opened with their key
+    pub unlocked_chests: HashSet<PlacementId>,
+    pub unlocked_doors: HashSet<PlacementId>,
+}
+
+/// State of the zones left by every player, by name
+#[derive(Default)]
+pub struct ZoneStore(pub HashMap<String, ZoneState>);
+
+/// Area moving the players walking in to a spawn point of a zone
+#[derive(Component)]
+pub struct Portal {
+    pub radius: f32,
+    pub zone: String,
+    pub spawn_point: String,
+}
+
+/// Player who just went through a portal, portals ignore the player until the timer finishes
+#[derive(Component)]
+pub struct PortalCooldown(pub Timer);
+
+/// Invisible wall closing a zone
+#[derive(Component)]
+pub struct ZoneWall(pub String);
@@ -0,0 +1,14 @@
+use std::path::PathBuf;
+
+use bevy::asset::FileAssetIo;
+
+use crate::ASSETS_FOLDER;
+
+/// Path of a file of the assets folder, resolved from the same root as the AssetServer
+/// so the files are found whatever the working directory
+pub fn asset_path(folder: &str, name: &str) -> PathBuf {
+    FileAssetIo::get_base_path()
+        .join(ASSETS_FOLDER)
+        .join(folder)
+        .join(format!("{}.ron", name))
+}
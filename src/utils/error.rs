@@ -25,4 +25,28 @@ pub enum ErrorMessage {
 
     #[error("No entity destination")]
     NoDestination,
+
+    #[error("Unknown recipe")]
+    UnknownRecipe,
+
+    #[error("Already crafting")]
+    AlreadyCrafting,
+
+    #[error("Missing ingredients")]
+    MissingIngredients,
+
+    #[error("Missing crafting tool")]
+    MissingTool,
+
+    #[error("No crafting station in range")]
+    NoCraftingStation,
+
+    #[error("Cannot read or write file")]
+    FileError(#[from] std::io::Error),
+
+    #[error("Invalid map file")]
+    MapFormatError(#[from] ron::error::SpannedError),
+
+    #[error("Cannot serialize save")]
+    SaveFormatError(#[from] ron::Error),
 }
@@ -1,2 +1,3 @@
+pub mod assets;
 pub mod error;
 pub mod vec;